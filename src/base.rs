@@ -1,5 +1,50 @@
 use super::DecodeError;
-use crate::util::*;
+use crate::{util::*, StackStr};
+
+/// `encode_*`/`decode_*` pair for a primitive integer, using native division instead of the byte-wise carry loop
+macro_rules! int_codec {
+    ($encode:ident, $decode:ident, $int:ty, $bits:literal) => {
+        #[doc = concat!("encodes a `", stringify!($int), "` as a number with no leading zeros (0 is the zero symbol).")]
+        ///
+        /// Base 2 is the widest output, so the capacity is the bit width of the integer.
+        fn $encode(mut value: $int) -> StackStr<$bits> {
+            let base = BASE as $int;
+            let mut digits = [0u8; $bits];
+            let mut len = 0;
+            loop {
+                digits[$bits - 1 - len] = Self::ALPHABET[(value % base) as usize];
+                len += 1;
+                value /= base;
+                if value == 0 {
+                    break;
+                }
+            }
+            StackStr::from_tail(digits, len)
+        }
+
+        #[doc = concat!("decodes a number into a `", stringify!($int), "`, leading zero symbols are allowed.")]
+        ///
+        /// Values that don't fit are rejected with [`DecodeError::Overflow`].
+        fn $decode<I: AsRef<[u8]>>(input: I) -> Result<$int, DecodeError> {
+            let input = input.as_ref();
+
+            if input.is_empty() {
+                return Err(DecodeError::InvalidLength(1));
+            }
+
+            let mut value: $int = 0;
+            for &ch in input {
+                let digit = Self::lookup_ascii(ch).ok_or(DecodeError::InvalidChar)?;
+                value = value
+                    .checked_mul(BASE as $int)
+                    .and_then(|value| value.checked_add(digit as $int))
+                    .ok_or(DecodeError::Overflow)?;
+            }
+
+            Ok(value)
+        }
+    };
+}
 
 /// ## Base-x for Ascii alphabets (which is most)
 pub trait Base<const BASE: usize> {
@@ -9,6 +54,9 @@ pub trait Base<const BASE: usize> {
 
     const BASE: usize = Self::ALPHABET.len();
 
+    /// whether the alphabet is in ascending ASCII order, required to implement [`SortedBase`]
+    const SORTED: bool = is_ascending(&Self::ALPHABET);
+
     /// decode input base encoding into buffer
     /// please do not pass in a non-empty buffer (this will output garbled data).
     ///
@@ -22,13 +70,35 @@ pub trait Base<const BASE: usize> {
     /// assert_eq!(&buf[..bytes_written], expected.as_slice());
     /// ```
     fn decode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        Self::decode_grouped_mut(input, buf, &[])
+    }
+
+    /// same as [`Base::decode_mut`] but any byte in `separators` is skipped wherever it appears,
+    /// so output from [`Base::encode_grouped_mut`] or [`Base::encode_wrapped_mut`] can be passed straight back in.
+    ///
+    /// ```rust
+    /// use smol_base_x::*;
+    ///
+    /// let mut buf = [0u8; 16];
+    /// let bytes_written = Base58Btc::decode_grouped_mut("Zi-Ca", &mut buf, b"-").unwrap();
+    ///
+    /// assert_eq!(&buf[..bytes_written], b"abc".as_slice());
+    /// ```
+    fn decode_grouped_mut<I: AsRef<[u8]>>(
+        input: I,
+        buf: &mut [u8],
+        separators: &[u8],
+    ) -> Result<usize, DecodeError> {
         let input = input.as_ref();
 
         if !input.is_ascii() {
             return Err(DecodeError::InvalidChar);
         }
         // thanks to https://sts10.github.io/2020/10/06/peeking-the-pivot.html for the great notes on iterators with look ahead
-        let mut iter = input.iter().peekable();
+        let mut iter = input
+            .iter()
+            .filter(|ch| !separators.contains(ch))
+            .peekable();
 
         // Skip leading spaces.
         while let Some(&&ch) = iter.peek() {
@@ -39,10 +109,10 @@ pub trait Base<const BASE: usize> {
             }
         }
 
-        // skip & count leading zeros (first alphabet char represents zero)
+        // skip & count leading zeros (any char the LUT maps to zero, so aliases count too)
         let mut zero_chars = 0;
         while let Some(&&ch) = iter.peek() {
-            if ch == Self::ALPHABET[0] {
+            if Self::lookup_ascii(ch) == Some(0) {
                 zero_chars += 1;
                 iter.next();
                 // Early check: if leading zeros alone exceed buffer, fail fast
@@ -128,65 +198,359 @@ pub trait Base<const BASE: usize> {
     /// ```
     fn encode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
         let input = input.as_ref();
+        encode_bytes(&Self::ALPHABET, input.iter().copied(), input.len(), buf)
+    }
 
-        // thanks to https://sts10.github.io/2020/10/06/peeking-the-pivot.html for the great notes on iterators with look ahead
-        let mut iter = input.iter().peekable();
+    /// encodes like [`Base::encode_mut`] then inserts `separator` between every `group` symbols,
+    /// e.g. `XXXX-XXXX-XXXX` for a group of 4. A `group` of 0 inserts nothing.
+    ///
+    /// `buf` has to fit the separators too, see [`grouped_size`] for sizing it.
+    ///
+    /// ```rust
+    /// use smol_base_x::*;
+    ///
+    /// let mut buf = [0u8; 16];
+    /// let bytes_written = Base58Btc::encode_grouped_mut("abc", &mut buf, 2, b'-').unwrap();
+    ///
+    /// assert_eq!(&buf[..bytes_written], b"Zi-Ca");
+    /// ```
+    fn encode_grouped_mut<I: AsRef<[u8]>>(
+        input: I,
+        buf: &mut [u8],
+        group: usize,
+        separator: u8,
+    ) -> Result<usize, DecodeError> {
+        let length = Self::encode_mut(input, buf)?;
+
+        if group == 0 || length == 0 {
+            return Ok(length);
+        }
 
-        let mut zeroes = 0;
-        // skip & count leading zeros
-        while let Some(&&ch) = iter.peek() {
-            if ch == 0 {
-                zeroes += 1;
-                iter.next();
-            } else {
-                break;
+        let total = length + (length - 1) / group;
+        if total > buf.len() {
+            return Err(DecodeError::InvalidLength(total));
+        }
+
+        // spread symbols out from the back so nothing is overwritten before it is moved
+        for i in (1..length).rev() {
+            buf[i + i / group] = buf[i];
+            if i % group == 0 {
+                buf[i + i / group - 1] = separator;
             }
         }
 
-        let size = encoded_size(BASE, iter.len());
+        Ok(total)
+    }
+
+    /// encodes like [`Base::encode_mut`] wrapping lines with `\n` every `width` symbols,
+    /// there is no trailing newline.
+    fn encode_wrapped_mut<I: AsRef<[u8]>>(
+        input: I,
+        buf: &mut [u8],
+        width: usize,
+    ) -> Result<usize, DecodeError> {
+        Self::encode_grouped_mut(input, buf, width, b'\n')
+    }
+
+    /// block mode: `input` is split into `block` byte chunks and each one is encoded on its own
+    /// into exactly [`block_encoded_size`] symbols (a shorter final chunk gets its own, smaller, width).
+    ///
+    /// Since blocks don't carry into each other encoding is linear in the input length,
+    /// and input can be encoded a piece at a time as long as every piece but the last is a multiple of `block`.
+    /// This is **not** compatible with [`Base::encode_mut`].
+    ///
+    /// A `block` of 0 or larger than [`MAX_BLOCK_SIZE`] is rejected with [`DecodeError::InvalidLength`]
+    /// holding the nearest allowed size, output is symbols written
+    ///
+    /// ```rust
+    /// use smol_base_x::*;
+    ///
+    /// let mut buf = [0u8; 16];
+    /// let written = Base62::encode_blocks_mut("abcd", &mut buf, 3).unwrap();
+    /// // 3 bytes always take 5 base62 symbols, the trailing byte 2
+    /// assert_eq!(written, 7);
+    ///
+    /// let mut decoded = [0u8; 16];
+    /// let len = Base62::decode_blocks_mut(&buf[..written], &mut decoded, 3).unwrap();
+    /// assert_eq!(&decoded[..len], b"abcd");
+    /// ```
+    fn encode_blocks_mut<I: AsRef<[u8]>>(
+        input: I,
+        buf: &mut [u8],
+        block: usize,
+    ) -> Result<usize, DecodeError> {
+        let input = input.as_ref();
+        if block == 0 || block > MAX_BLOCK_SIZE {
+            return Err(DecodeError::InvalidLength(block.clamp(1, MAX_BLOCK_SIZE)));
+        }
 
-        // buf is too small to fit string
+        let full = block_encoded_size(BASE, block);
+        let size = input.len() / block * full + block_encoded_size(BASE, input.len() % block);
         if size > buf.len() {
             return Err(DecodeError::InvalidLength(size));
         }
 
-        let mut length = 0;
+        let mut written = 0;
+        for chunk in input.chunks(block) {
+            let symbols = if chunk.len() == block {
+                full
+            } else {
+                block_encoded_size(BASE, chunk.len())
+            };
+            encode_fixed(&Self::ALPHABET, chunk, &mut buf[written..written + symbols])?;
+            written += symbols;
+        }
 
-        for &ch in iter {
-            let mut carry = ch as usize;
+        Ok(written)
+    }
 
-            let mut i = 0;
-            let mut rev = buf.iter_mut().rev();
-            while i < length || carry != 0 {
-                match rev.next() {
-                    Some(it) => {
-                        carry += 256 * (*it as usize);
-                        *it = (carry % BASE) as u8;
-                        carry /= BASE;
-                        i += 1;
-                    }
-                    None => break,
-                }
+    /// inverse of [`Base::encode_blocks_mut`], `block` has to match what was used to encode
+    /// and is checked the same way.
+    ///
+    /// Blocks whose value doesn't fit in their byte size are rejected with [`DecodeError::Overflow`],
+    /// output is bytes written
+    fn decode_blocks_mut<I: AsRef<[u8]>>(
+        input: I,
+        buf: &mut [u8],
+        block: usize,
+    ) -> Result<usize, DecodeError> {
+        let input = input.as_ref();
+        if block == 0 || block > MAX_BLOCK_SIZE {
+            return Err(DecodeError::InvalidLength(block.clamp(1, MAX_BLOCK_SIZE)));
+        }
+
+        let full = block_encoded_size(BASE, block);
+        let partial = block_decoded_size(BASE, input.len() % full).ok_or(
+            DecodeError::InvalidLength(input.len().next_multiple_of(full)),
+        )?;
+
+        let size = input.len() / full * block + partial;
+        if size > buf.len() {
+            return Err(DecodeError::InvalidLength(size));
+        }
+
+        let mut written = 0;
+        for chunk in input.chunks(full) {
+            let bytes = if chunk.len() == full { block } else { partial };
+            decode_fixed::<BASE, Self>(chunk, &mut buf[written..written + bytes])?;
+            written += bytes;
+        }
+
+        Ok(written)
+    }
+
+    /// encodes `input` as one number left padded with the zero symbol to the widest any input
+    /// of the same length can be ([`block_encoded_size`]), so the output length only depends on the input length.
+    ///
+    /// Inputs longer than [`MAX_BLOCK_SIZE`] are rejected with [`DecodeError::InvalidLength`],
+    /// output is symbols written
+    ///
+    /// ```rust
+    /// use smol_base_x::*;
+    ///
+    /// let mut buf = [0u8; 16];
+    /// let written = Base58Btc::encode_fixed_mut([0, 0, 1], &mut buf).unwrap();
+    /// assert_eq!(&buf[..written], b"11112");
+    /// ```
+    fn encode_fixed_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        let input = input.as_ref();
+
+        if input.len() > MAX_BLOCK_SIZE {
+            return Err(DecodeError::InvalidLength(MAX_BLOCK_SIZE));
+        }
+
+        let size = block_encoded_size(BASE, input.len());
+        if size > buf.len() {
+            return Err(DecodeError::InvalidLength(size));
+        }
+
+        encode_fixed(&Self::ALPHABET, input, &mut buf[..size])?;
+        Ok(size)
+    }
+
+    /// inverse of [`Base::encode_fixed_mut`], the byte length is worked out from the width.
+    ///
+    /// Values too large for that many bytes are rejected with [`DecodeError::Overflow`],
+    /// output is bytes written
+    fn decode_fixed_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        let input = input.as_ref();
+
+        let size = block_decoded_size(BASE, input.len())
+            .ok_or(DecodeError::InvalidLength(input.len() + 1))?;
+        if size > buf.len() {
+            return Err(DecodeError::InvalidLength(size));
+        }
+
+        decode_fixed::<BASE, Self>(input, &mut buf[..size])?;
+        Ok(size)
+    }
+
+    /// fills `buf` with `len` symbols picked uniformly at random, output is symbols written.
+    ///
+    /// Each symbol takes the low bits of a random byte and retries when they land past the end
+    /// of the alphabet, so there is no modulo bias. See [`symbols_for_bits`] for picking `len`.
+    ///
+    /// ```rust
+    /// use rand::{rngs::StdRng, SeedableRng};
+    /// use smol_base_x::{util::symbols_for_bits, *};
+    ///
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let len = symbols_for_bits(62, 128).unwrap();
+    /// assert_eq!(len, 22);
+    ///
+    /// let mut buf = [0u8; 32];
+    /// let written = Base62::random_string(&mut rng, len, &mut buf).unwrap();
+    /// assert!(buf[..written].iter().all(u8::is_ascii_alphanumeric));
+    /// ```
+    #[cfg(feature = "rand_core")]
+    fn random_string<R: rand_core::RngCore + ?Sized>(
+        rng: &mut R,
+        len: usize,
+        buf: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        if len > buf.len() {
+            return Err(DecodeError::InvalidLength(len));
+        }
+
+        let mask = BASE.next_power_of_two() - 1;
+        let mut random = [0u8; 64];
+        let mut used = random.len();
+
+        let mut written = 0;
+        while written < len {
+            if used == random.len() {
+                rng.fill_bytes(&mut random);
+                used = 0;
             }
 
-            length = i;
-            if length > buf.len() {
-                return Err(DecodeError::InvalidLength(length));
+            let index = random[used] as usize & mask;
+            used += 1;
+            if index < BASE {
+                buf[written] = Self::ALPHABET[index];
+                written += 1;
             }
+        }
 
-            assert!(carry == 0);
+        Ok(written)
+    }
+
+    int_codec!(encode_u32, decode_u32, u32, 32);
+    int_codec!(encode_u64, decode_u64, u64, 64);
+    int_codec!(encode_u128, decode_u128, u128, 128);
+
+    /// compares two encoded strings by the numbers they hold, so `"10"` is greater than `"9"`
+    /// and leading zero symbols make no difference.
+    ///
+    /// ```rust
+    /// use core::cmp::Ordering;
+    /// use smol_base_x::*;
+    ///
+    /// assert_eq!(Base62::compare("Z", "10").unwrap(), Ordering::Less);
+    /// assert_eq!(Base62::compare("0010", "10").unwrap(), Ordering::Equal);
+    /// ```
+    fn compare<A: AsRef<[u8]>, B: AsRef<[u8]>>(
+        a: A,
+        b: B,
+    ) -> Result<core::cmp::Ordering, DecodeError> {
+        let (a, b) = (a.as_ref(), b.as_ref());
+        if !a
+            .iter()
+            .chain(b)
+            .all(|&ch| Self::lookup_ascii(ch).is_some())
+        {
+            return Err(DecodeError::InvalidChar);
         }
 
-        length += zeroes;
+        let skip_zeroes = |s: &[u8]| {
+            s.iter()
+                .take_while(|&&ch| Self::LUT[ch as usize] == 0)
+                .count()
+        };
+        let a = &a[skip_zeroes(a)..];
+        let b = &b[skip_zeroes(b)..];
+
+        // same length, so the first differing digit decides
+        Ok(a.len().cmp(&b.len()).then_with(|| {
+            a.iter()
+                .map(|&ch| Self::LUT[ch as usize])
+                .cmp(b.iter().map(|&ch| Self::LUT[ch as usize]))
+        }))
+    }
 
-        buf.rotate_left(buf.len() - length);
+    /// adds `value` to the number in `buf` in place, carrying through the alphabet.
+    ///
+    /// The width is fixed, if the sum needs more symbols than `buf` has this fails with
+    /// [`DecodeError::Overflow`] and leaves `buf` as it was.
+    ///
+    /// ```rust
+    /// use smol_base_x::*;
+    ///
+    /// let mut id = *b"00zz";
+    /// Base36::add_mut(&mut id, 2).unwrap();
+    /// assert_eq!(&id, b"0101");
+    ///
+    /// let mut full = *b"zz";
+    /// assert!(matches!(Base36::increment_mut(&mut full), Err(DecodeError::Overflow)));
+    /// assert_eq!(&full, b"zz");
+    /// ```
+    fn add_mut(buf: &mut [u8], value: u64) -> Result<(), DecodeError> {
+        if buf.iter().any(|&ch| Self::lookup_ascii(ch).is_none()) {
+            return Err(DecodeError::InvalidChar);
+        }
 
-        // translate index into alphabet letter
-        for i in buf.iter_mut().take(length) {
-            *i = Self::ALPHABET[*i as usize];
+        // a dry run first, so an overflow doesn't leave a half written buf behind
+        let mut carry = value as u128;
+        for &ch in buf.iter().rev() {
+            if carry == 0 {
+                break;
+            }
+            carry = (Self::LUT[ch as usize] as u128 + carry) / BASE as u128;
+        }
+        if carry != 0 {
+            return Err(DecodeError::Overflow);
         }
 
-        Ok(length)
+        let mut carry = value as u128;
+        for ch in buf.iter_mut().rev() {
+            if carry == 0 {
+                break;
+            }
+            let sum = Self::LUT[*ch as usize] as u128 + carry;
+            *ch = Self::ALPHABET[(sum % BASE as u128) as usize];
+            carry = sum / BASE as u128;
+        }
+
+        Ok(())
+    }
+
+    /// [`Base::add_mut`] of one
+    fn increment_mut(buf: &mut [u8]) -> Result<(), DecodeError> {
+        Self::add_mut(buf, 1)
+    }
+
+    /// subtracts one from the number in `buf` in place, borrowing through the alphabet.
+    ///
+    /// A buf of all zero symbols can't go any lower, that fails with [`DecodeError::Overflow`]
+    /// and leaves `buf` as it was.
+    fn decrement_mut(buf: &mut [u8]) -> Result<(), DecodeError> {
+        if buf.iter().any(|&ch| Self::lookup_ascii(ch).is_none()) {
+            return Err(DecodeError::InvalidChar);
+        }
+        if buf.iter().all(|&ch| Self::LUT[ch as usize] == 0) {
+            return Err(DecodeError::Overflow);
+        }
+
+        for ch in buf.iter_mut().rev() {
+            match Self::LUT[*ch as usize] {
+                0 => *ch = Self::ALPHABET[BASE - 1],
+                digit => {
+                    *ch = Self::ALPHABET[digit as usize - 1];
+                    break;
+                }
+            }
+        }
+
+        Ok(())
     }
 
     /// Lookup the value for the current char index
@@ -361,3 +725,254 @@ pub trait Base<const BASE: usize> {
         Ok((arr, length))
     }
 }
+
+/// ## [`Base`] for alphabets in ascending ASCII order
+///
+/// Only implement this when [`Base::SORTED`] holds, every implementation in this crate is checked
+/// in the tests. Alphabets without it, like [`crate::Base58Flickr`], don't get
+/// [`SortedBase::encode_sortable_mut`] at all.
+pub trait SortedBase<const BASE: usize>: Base<BASE> {
+    /// [`Base::encode_fixed_mut`] for alphabets where that keeps the order: for inputs of the same
+    /// length, comparing the outputs as strings (or bytes) matches comparing the inputs.
+    ///
+    /// ```rust
+    /// use smol_base_x::*;
+    ///
+    /// let mut low = [0u8; 8];
+    /// let mut high = [0u8; 8];
+    /// Base36::encode_sortable_mut(9u32.to_be_bytes(), &mut low).unwrap();
+    /// Base36::encode_sortable_mut(10u32.to_be_bytes(), &mut high).unwrap();
+    /// assert!(low < high);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use smol_base_x::*;
+    ///
+    /// let mut buf = [0u8; 8];
+    /// // lower case sorts after upper case, but comes first in the alphabet
+    /// Base58Flickr::encode_sortable_mut([1, 2], &mut buf).unwrap();
+    /// ```
+    fn encode_sortable_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        Self::encode_fixed_mut(input, buf)
+    }
+}
+
+/// converts a string of one [`Base`] straight into another without going through bytes,
+/// output is symbols written.
+///
+/// Bases of the same size are mapped symbol for symbol, otherwise the digits go through the same
+/// carry loop as [`Base::encode_mut`]. Leading zero symbols carry over one for one either way, so
+/// the output is what decoding with `F` and encoding with `T` gives.
+///
+/// ```rust
+/// use smol_base_x::*;
+///
+/// let mut buf = [0u8; 16];
+/// let written = transcode::<58, Base58Flickr, 58, Base58Btc, _>("1ZiCa", &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"1zJdA");
+///
+/// let written = transcode::<62, Base62, 36, Base36, _>("0zz", &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"01p9");
+/// ```
+pub fn transcode<
+    const FROM: usize,
+    F: Base<FROM> + ?Sized,
+    const TO: usize,
+    T: Base<TO> + ?Sized,
+    I: AsRef<[u8]>,
+>(
+    input: I,
+    buf: &mut [u8],
+) -> Result<usize, DecodeError> {
+    let input = input.as_ref();
+
+    if FROM == TO {
+        if input.len() > buf.len() {
+            return Err(DecodeError::InvalidLength(input.len()));
+        }
+        for (out, &ch) in buf.iter_mut().zip(input) {
+            *out = T::ALPHABET[F::lookup_ascii(ch).ok_or(DecodeError::InvalidChar)?];
+        }
+        return Ok(input.len());
+    }
+
+    let zeroes = input
+        .iter()
+        .take_while(|&&ch| F::lookup_ascii(ch) == Some(0))
+        .count();
+
+    let size = zeroes + transcoded_size(FROM, TO, input.len() - zeroes);
+    if size > buf.len() {
+        return Err(DecodeError::InvalidLength(size));
+    }
+
+    let mut length = 0;
+    for &ch in &input[zeroes..] {
+        let mut carry = F::lookup_ascii(ch).ok_or(DecodeError::InvalidChar)?;
+
+        let mut i = 0;
+        // `size` is only an estimate, so the digits must also stop short of the zero symbols
+        let mut rev = buf[zeroes..].iter_mut().rev();
+        while i < length || carry != 0 {
+            match rev.next() {
+                Some(it) => {
+                    // anything past `length` hasn't been written yet
+                    let digit = if i < length { *it as usize } else { 0 };
+                    carry += FROM * digit;
+                    *it = (carry % TO) as u8;
+                    carry /= TO;
+                    i += 1;
+                }
+                None => return Err(DecodeError::InvalidLength(zeroes + i + 1)),
+            }
+        }
+
+        length = i;
+    }
+
+    // digits sit at the end of buf, move them behind the zero symbols
+    buf.copy_within(buf.len() - length.., zeroes);
+    buf[..zeroes].fill(T::ALPHABET[0]);
+    for i in buf[zeroes..zeroes + length].iter_mut() {
+        *i = T::ALPHABET[*i as usize];
+    }
+
+    Ok(zeroes + length)
+}
+
+/// carry loop behind [`Base::encode_mut`], split out so callers can encode several slices
+/// (prefixes, payloads, checksums) as one number without copying them into a scratch buffer.
+///
+/// `len` is the total number of bytes `input` yields.
+pub(crate) fn encode_bytes<const BASE: usize>(
+    alphabet: &[u8; BASE],
+    input: impl Iterator<Item = u8>,
+    len: usize,
+    buf: &mut [u8],
+) -> Result<usize, DecodeError> {
+    // thanks to https://sts10.github.io/2020/10/06/peeking-the-pivot.html for the great notes on iterators with look ahead
+    let mut iter = input.peekable();
+
+    let mut zeroes = 0;
+    // skip & count leading zeros
+    while let Some(&ch) = iter.peek() {
+        if ch == 0 {
+            zeroes += 1;
+            iter.next();
+        } else {
+            break;
+        }
+    }
+
+    let size = encoded_size(BASE, len - zeroes);
+
+    // buf is too small to fit string
+    if size > buf.len() {
+        return Err(DecodeError::InvalidLength(size));
+    }
+
+    let mut length = 0;
+
+    for ch in iter {
+        let mut carry = ch as usize;
+
+        let mut i = 0;
+        let mut rev = buf.iter_mut().rev();
+        while i < length || carry != 0 {
+            match rev.next() {
+                Some(it) => {
+                    carry += 256 * (*it as usize);
+                    *it = (carry % BASE) as u8;
+                    carry /= BASE;
+                    i += 1;
+                }
+                None => break,
+            }
+        }
+
+        length = i;
+        if length > buf.len() {
+            return Err(DecodeError::InvalidLength(length));
+        }
+
+        assert!(carry == 0);
+    }
+
+    length += zeroes;
+
+    buf.rotate_left(buf.len() - length);
+
+    // translate index into alphabet letter
+    for i in buf.iter_mut().take(length) {
+        *i = alphabet[*i as usize];
+    }
+
+    Ok(length)
+}
+
+/// same carry loop as [`encode_bytes`] but the number always fills all of `buf`,
+/// left padded with `alphabet[0]` instead of counting leading zero bytes.
+///
+/// errors with [`DecodeError::Overflow`] when `input` doesn't fit in `buf.len()` symbols.
+pub(crate) fn encode_fixed<const BASE: usize>(
+    alphabet: &[u8; BASE],
+    input: &[u8],
+    buf: &mut [u8],
+) -> Result<(), DecodeError> {
+    if fixed_digits::<BASE>(input, buf) {
+        return Err(DecodeError::Overflow);
+    }
+
+    for i in buf.iter_mut() {
+        *i = alphabet[*i as usize];
+    }
+
+    Ok(())
+}
+
+/// the carry loop behind [`encode_fixed`], without the alphabet: `buf` is left holding the digits
+/// of `input mod BASE^buf.len()`, most significant first.
+///
+/// returns whether anything was carried out of the top digit (the value didn't fit).
+pub(crate) fn fixed_digits<const BASE: usize>(input: &[u8], buf: &mut [u8]) -> bool {
+    buf.fill(0);
+
+    let mut overflow = false;
+    for &byte in input {
+        let mut carry = byte as usize;
+        for it in buf.iter_mut().rev() {
+            carry += 256 * (*it as usize);
+            *it = (carry % BASE) as u8;
+            carry /= BASE;
+        }
+
+        overflow |= carry != 0;
+    }
+
+    overflow
+}
+
+/// inverse of [`encode_fixed`], the decoded number fills all of `buf` (left padded with zero bytes).
+///
+/// errors with [`DecodeError::Overflow`] when the value doesn't fit in `buf.len()` bytes.
+pub(crate) fn decode_fixed<const BASE: usize, B: Base<BASE> + ?Sized>(
+    input: &[u8],
+    buf: &mut [u8],
+) -> Result<(), DecodeError> {
+    buf.fill(0);
+
+    for &ch in input {
+        let mut carry = B::lookup_ascii(ch).ok_or(DecodeError::InvalidChar)?;
+        for it in buf.iter_mut().rev() {
+            carry += BASE * (*it as usize);
+            *it = (carry % 256) as u8;
+            carry /= 256;
+        }
+
+        if carry != 0 {
+            return Err(DecodeError::Overflow);
+        }
+    }
+
+    Ok(())
+}
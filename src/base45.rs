@@ -0,0 +1,91 @@
+use crate::{util::gen_lut, DecodeError};
+
+/// RFC 9285 Base45, every 2 bytes become 3 chars of the QR code alphanumeric set (least significant first).
+///
+/// Note that space is part of the alphabet, so unlike [`crate::Base::decode_mut`] nothing is trimmed.
+///
+/// ```rust
+/// use smol_base_x::base45::Base45;
+///
+/// let mut buf = [0u8; 16];
+/// let written = Base45::encode_mut("Hello!!", &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"%69 VD92EX0");
+/// ```
+#[derive(Debug, Default)]
+pub struct Base45;
+
+impl Base45 {
+    pub const ALPHABET: [u8; 45] = *b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ $%*+-./:";
+
+    const LUT: [i8; 256] = gen_lut(&Self::ALPHABET);
+
+    /// exact chars `bytes` encodes to
+    pub fn encoded_size(bytes: usize) -> usize {
+        bytes / 2 * 3 + bytes % 2 * 2
+    }
+
+    /// output is chars written
+    pub fn encode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        let input = input.as_ref();
+
+        let size = Self::encoded_size(input.len());
+        if size > buf.len() {
+            return Err(DecodeError::InvalidLength(size));
+        }
+
+        let mut written = 0;
+        for chunk in input.chunks(2) {
+            let (mut value, chars) = match *chunk {
+                [a, b] => ((a as usize) << 8 | b as usize, 3),
+                [a] => (a as usize, 2),
+                _ => unreachable!(),
+            };
+
+            for out in &mut buf[written..written + chars] {
+                *out = Self::ALPHABET[value % 45];
+                value /= 45;
+            }
+            written += chars;
+        }
+
+        Ok(written)
+    }
+
+    /// output is bytes written.
+    ///
+    /// Triplets above `0xFFFF` (and a trailing pair above `0xFF`) are rejected with [`DecodeError::Overflow`].
+    pub fn decode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        let input = input.as_ref();
+
+        if input.len() % 3 == 1 {
+            return Err(DecodeError::InvalidLength(input.len() + 1));
+        }
+        let size = input.len() / 3 * 2 + input.len() % 3 / 2;
+        if size > buf.len() {
+            return Err(DecodeError::InvalidLength(size));
+        }
+
+        let mut written = 0;
+        for chunk in input.chunks(3) {
+            let mut value = 0;
+            for &ch in chunk.iter().rev() {
+                let digit = match Self::LUT[ch as usize] {
+                    -1 => return Err(DecodeError::InvalidChar),
+                    i => i as usize,
+                };
+                value = value * 45 + digit;
+            }
+
+            if chunk.len() == 3 {
+                let pair = u16::try_from(value).map_err(|_| DecodeError::Overflow)?;
+                buf[written..written + 2].copy_from_slice(&pair.to_be_bytes());
+                written += 2;
+            } else {
+                buf[written] = u8::try_from(value).map_err(|_| DecodeError::Overflow)?;
+                written += 1;
+            }
+        }
+
+        Ok(written)
+    }
+}
@@ -0,0 +1,60 @@
+use crate::{
+    base::{encode_bytes, Base},
+    sha256::double_sha256,
+    Base58Btc, DecodeError,
+};
+
+/// bytes of `SHA256(SHA256(version || payload))` appended before encoding
+pub const CHECKSUM_LEN: usize = 4;
+
+/// encodes `version || payload || checksum` with [`Base58Btc`], returning chars written.
+///
+/// ```rust
+/// use smol_base_x::base58check;
+///
+/// let payload = [0u8; 20];
+/// let mut buf = [0u8; 64];
+/// let written = base58check::encode_check(0, &payload, &mut buf).unwrap();
+///
+/// assert_eq!(&buf[..written], b"1111111111111111111114oLvT2");
+/// ```
+pub fn encode_check(version: u8, payload: &[u8], buf: &mut [u8]) -> Result<usize, DecodeError> {
+    let version = [version];
+    let checksum = double_sha256(&[&version, payload]);
+
+    let bytes = version
+        .iter()
+        .chain(payload)
+        .chain(&checksum[..CHECKSUM_LEN])
+        .copied();
+
+    encode_bytes(
+        &Base58Btc::ALPHABET,
+        bytes,
+        1 + payload.len() + CHECKSUM_LEN,
+        buf,
+    )
+}
+
+/// decodes a Base58Check string and verifies its checksum.
+///
+/// output is `(version, payload bytes written)`, the payload is moved to the start of `buf`.
+/// `buf` needs room for the version and checksum as well (5 bytes more than the payload).
+pub fn decode_check<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<(u8, usize), DecodeError> {
+    let written = Base58Btc::decode_mut(input, buf)?;
+
+    if written < 1 + CHECKSUM_LEN {
+        return Err(DecodeError::InvalidLength(1 + CHECKSUM_LEN));
+    }
+
+    let (data, checksum) = buf[..written].split_at(written - CHECKSUM_LEN);
+    if double_sha256(&[data])[..CHECKSUM_LEN] != *checksum {
+        return Err(DecodeError::ChecksumMismatch);
+    }
+
+    let version = buf[0];
+    let payload_len = written - 1 - CHECKSUM_LEN;
+    buf.copy_within(1..1 + payload_len, 0);
+
+    Ok((version, payload_len))
+}
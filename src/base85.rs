@@ -0,0 +1,262 @@
+use crate::{util::gen_lut, Base85Rfc1924, DecodeError};
+
+/// Adobe Ascii85 (btoa), `!` through `u` with `z` standing in for a group of four zero bytes.
+///
+/// ```rust
+/// use smol_base_x::base85::Ascii85;
+///
+/// let mut buf = [0u8; 32];
+/// let written = Ascii85::encode_delimited_mut(b"\0\0\0\0abc", &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"<~z@:E^~>");
+/// ```
+#[derive(Debug, Default)]
+pub struct Ascii85;
+
+impl Ascii85 {
+    /// upper bound of the chars `bytes` encodes to (without delimiters)
+    pub fn encoded_size(bytes: usize) -> usize {
+        bytes / 4 * 5
+            + if bytes.is_multiple_of(4) {
+                0
+            } else {
+                bytes % 4 + 1
+            }
+    }
+
+    /// encodes without the `<~ ~>` delimiters, output is chars written
+    pub fn encode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        let input = input.as_ref();
+
+        let size = Self::encoded_size(input.len());
+        if size > buf.len() {
+            return Err(DecodeError::InvalidLength(size));
+        }
+
+        let mut written = 0;
+        for chunk in input.chunks(4) {
+            if chunk == [0; 4] {
+                buf[written] = b'z';
+                written += 1;
+                continue;
+            }
+
+            // partial groups are padded with zeros and only `len + 1` chars are kept
+            let mut group = [0u8; 4];
+            group[..chunk.len()].copy_from_slice(chunk);
+            let digits = encode_group(u32::from_be_bytes(group));
+            for &digit in &digits[..chunk.len() + 1] {
+                buf[written] = b'!' + digit;
+                written += 1;
+            }
+        }
+
+        Ok(written)
+    }
+
+    /// encodes wrapped in `<~` and `~>`
+    pub fn encode_delimited_mut<I: AsRef<[u8]>>(
+        input: I,
+        buf: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        let input = input.as_ref();
+
+        let size = Self::encoded_size(input.len()) + 4;
+        if size > buf.len() {
+            return Err(DecodeError::InvalidLength(size));
+        }
+
+        buf[..2].copy_from_slice(b"<~");
+        let written = Self::encode_mut(input, &mut buf[2..])?;
+        buf[2 + written..4 + written].copy_from_slice(b"~>");
+
+        Ok(written + 4)
+    }
+
+    /// decodes with or without the `<~ ~>` delimiters, whitespace anywhere is ignored.
+    ///
+    /// output is bytes written
+    pub fn decode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        let mut input = input.as_ref().trim_ascii();
+
+        if let Some(rest) = input.strip_prefix(b"<~") {
+            input = rest.strip_suffix(b"~>").ok_or(DecodeError::InvalidChar)?;
+        } else if let Some(rest) = input.strip_suffix(b"~>") {
+            input = rest;
+        }
+
+        let mut group = [0u8; 5];
+        let mut filled = 0;
+        let mut written = 0;
+
+        for &ch in input {
+            match ch {
+                b' ' | b'\t' | b'\n' | b'\r' | b'\x0c' | b'\0' => continue,
+                b'z' if filled == 0 => {
+                    if written + 4 > buf.len() {
+                        return Err(DecodeError::InvalidLength(written + 4));
+                    }
+                    buf[written..written + 4].fill(0);
+                    written += 4;
+                }
+                b'!'..=b'u' => {
+                    group[filled] = ch - b'!';
+                    filled += 1;
+
+                    if filled == 5 {
+                        if written + 4 > buf.len() {
+                            return Err(DecodeError::InvalidLength(written + 4));
+                        }
+                        buf[written..written + 4].copy_from_slice(&decode_group(&group)?);
+                        written += 4;
+                        filled = 0;
+                    }
+                }
+                _ => return Err(DecodeError::InvalidChar),
+            }
+        }
+
+        // a final group of n chars holds n - 1 bytes, and is padded with `u` to decode
+        if filled == 1 {
+            return Err(DecodeError::InvalidLength(written + 2));
+        }
+        if filled > 1 {
+            let bytes = filled - 1;
+            if written + bytes > buf.len() {
+                return Err(DecodeError::InvalidLength(written + bytes));
+            }
+            group[filled..].fill(84);
+            buf[written..written + bytes].copy_from_slice(&decode_group(&group)?[..bytes]);
+            written += bytes;
+        }
+
+        Ok(written)
+    }
+}
+
+/// ZeroMQ Z85, only encodes whole groups of four bytes.
+///
+/// ```rust
+/// use smol_base_x::base85::Z85;
+///
+/// let mut buf = [0u8; 16];
+/// let written = Z85::encode_mut([0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B], &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"HelloWorld");
+/// ```
+#[derive(Debug, Default)]
+pub struct Z85;
+
+impl Z85 {
+    pub const ALPHABET: [u8; 85] =
+        *b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+    const LUT: [i8; 256] = gen_lut(&Self::ALPHABET);
+
+    /// input length must be a multiple of 4, output is chars written
+    pub fn encode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        let input = input.as_ref();
+
+        if input.len() % 4 != 0 {
+            return Err(DecodeError::InvalidLength(input.len().next_multiple_of(4)));
+        }
+        let size = input.len() / 4 * 5;
+        if size > buf.len() {
+            return Err(DecodeError::InvalidLength(size));
+        }
+
+        for (chunk, out) in input.chunks_exact(4).zip(buf.chunks_exact_mut(5)) {
+            let digits = encode_group(u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]));
+            for (o, digit) in out.iter_mut().zip(digits) {
+                *o = Self::ALPHABET[digit as usize];
+            }
+        }
+
+        Ok(size)
+    }
+
+    /// input length must be a multiple of 5, output is bytes written
+    pub fn decode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        let input = input.as_ref();
+
+        if input.len() % 5 != 0 {
+            return Err(DecodeError::InvalidLength(input.len().next_multiple_of(5)));
+        }
+        let size = input.len() / 5 * 4;
+        if size > buf.len() {
+            return Err(DecodeError::InvalidLength(size));
+        }
+
+        for (chunk, out) in input.chunks_exact(5).zip(buf.chunks_exact_mut(4)) {
+            let mut group = [0u8; 5];
+            for (g, &ch) in group.iter_mut().zip(chunk) {
+                *g = match Self::LUT[ch as usize] {
+                    -1 => return Err(DecodeError::InvalidChar),
+                    i => i as u8,
+                };
+            }
+            out.copy_from_slice(&decode_group(&group)?);
+        }
+
+        Ok(size)
+    }
+}
+
+impl Base85Rfc1924 {
+    /// encodes an IPv6 address (as its 16 network order bytes) into exactly 20 chars, per RFC 1924.
+    ///
+    /// ```rust
+    /// use smol_base_x::Base85Rfc1924;
+    ///
+    /// // 1080::8:800:200C:417A
+    /// let addr = 0x1080_0000_0000_0000_0008_0800_200C_417Au128.to_be_bytes();
+    /// assert_eq!(&Base85Rfc1924::encode_ipv6(addr), b"4)+k&C#VzJ4br>0wv%Yp");
+    /// ```
+    pub fn encode_ipv6(addr: [u8; 16]) -> [u8; 20] {
+        let mut n = u128::from_be_bytes(addr);
+        let mut out = [0u8; 20];
+        for o in out.iter_mut().rev() {
+            *o = <Self as crate::Base<85>>::ALPHABET[(n % 85) as usize];
+            n /= 85;
+        }
+        out
+    }
+
+    /// decodes the 20 char RFC 1924 form of an IPv6 address
+    pub fn decode_ipv6<I: AsRef<[u8]>>(input: I) -> Result<[u8; 16], DecodeError> {
+        let input = input.as_ref();
+
+        if input.len() != 20 {
+            return Err(DecodeError::InvalidLength(20));
+        }
+
+        let mut n = 0u128;
+        for &ch in input {
+            let digit =
+                <Self as crate::Base<85>>::lookup_ascii(ch).ok_or(DecodeError::InvalidChar)?;
+            n = n
+                .checked_mul(85)
+                .and_then(|n| n.checked_add(digit as u128))
+                .ok_or(DecodeError::Overflow)?;
+        }
+
+        Ok(n.to_be_bytes())
+    }
+}
+
+/// big-endian base 85 digits of a group
+fn encode_group(mut value: u32) -> [u8; 5] {
+    let mut digits = [0u8; 5];
+    for d in digits.iter_mut().rev() {
+        *d = (value % 85) as u8;
+        value /= 85;
+    }
+    digits
+}
+
+/// `85^5` is larger than `u32::MAX`, so some groups don't fit in four bytes
+fn decode_group(digits: &[u8; 5]) -> Result<[u8; 4], DecodeError> {
+    let value = digits.iter().fold(0u64, |acc, &d| acc * 85 + d as u64);
+
+    u32::try_from(value)
+        .map(u32::to_be_bytes)
+        .map_err(|_| DecodeError::Overflow)
+}
@@ -1,4 +1,7 @@
-use crate::Base;
+use crate::{
+    util::{base91_encoded_size, gen_lut},
+    Base, DecodeError, SortedBase,
+};
 
 #[derive(Debug, Default)]
 pub struct Base58Btc;
@@ -28,4 +31,267 @@ base!(Base67, 67 b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz012345678
 
 // etc
 base!(Base10, 10 b"0123456789");
+// shortuuid's alphabet, base58 without `1` and `l` (it is already missing `0`, `O` and `I`)
+base!(Base57, 57 b"23456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+// base62 in ASCII order, as used by KSUID
+base!(Base62Sorted, 62 b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz");
+base!(Base36Upper, 36 b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ");
 base!(Base58Flickr, 58 b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ");
+/// Crockford's base32: decoding is case-insensitive, reads `I`/`L` as `1` and `O` as `0`,
+/// and skips hyphens. See [`crate::crockford`] for integers and the check symbol.
+///
+/// ```rust
+/// use smol_base_x::*;
+///
+/// let mut buf = [0u8; 16];
+/// let written = Base32Crockford::decode_mut("3i-Yo", &mut buf).unwrap();
+///
+/// let mut reencoded = [0u8; 16];
+/// let len = Base32Crockford::encode_mut(&buf[..written], &mut reencoded).unwrap();
+/// assert_eq!(&reencoded[..len], b"31Y0");
+/// ```
+#[derive(Debug, Default)]
+pub struct Base32Crockford;
+
+impl Base<32> for Base32Crockford {
+    const ALPHABET: [u8; 32] = *b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+    const LUT: [i8; 256] = crockford_lut(&Self::ALPHABET);
+
+    fn decode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        Self::decode_grouped_mut(input, buf, b"-")
+    }
+}
+
+/// [`gen_lut`] plus lower case and the `I`/`L`/`O` aliases
+pub(crate) const fn crockford_lut<const BASE: usize>(alphabet: &[u8; BASE]) -> [i8; 256] {
+    let mut lut = gen_lut(alphabet);
+
+    let mut i = 0;
+    while i < BASE {
+        lut[alphabet[i].to_ascii_lowercase() as usize] = i as i8;
+        i += 1;
+    }
+
+    lut[b'I' as usize] = 1;
+    lut[b'i' as usize] = 1;
+    lut[b'L' as usize] = 1;
+    lut[b'l' as usize] = 1;
+    lut[b'O' as usize] = 0;
+    lut[b'o' as usize] = 0;
+
+    lut
+}
+
+// alphabets in ascending ASCII order
+impl SortedBase<58> for Base58Btc {}
+impl SortedBase<2> for Base2 {}
+impl SortedBase<8> for Base8 {}
+impl SortedBase<11> for Base11 {}
+impl SortedBase<36> for Base36 {}
+impl SortedBase<10> for Base10 {}
+impl SortedBase<57> for Base57 {}
+impl SortedBase<62> for Base62Sorted {}
+impl SortedBase<36> for Base36Upper {}
+impl SortedBase<32> for Base32Crockford {}
+
+// RFC 1924, see `Base85Rfc1924::encode_ipv6` for the fixed width address form
+base!(Base85Rfc1924, 85 b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz!#$%&()*+-;<=>?@^_`{|}~");
+
+/// basE91, packs 13 or 14 bits into every pair of chars for roughly 23% overhead.
+///
+/// This is not a [`Base`], the input is consumed as a bit stream so it can be encoded in pieces,
+/// see [`Base91Encoder`] and [`Base91Decoder`].
+///
+/// ```rust
+/// use smol_base_x::Base91;
+///
+/// let mut buf = [0u8; 16];
+/// let written = Base91::encode_mut("test", &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"fPNKd");
+/// ```
+#[derive(Debug, Default)]
+pub struct Base91;
+
+impl Base91 {
+    pub const ALPHABET: [u8; 91] =
+        *b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789!#$%&()*+,./:;<=>?@[]^_`{|}~\"";
+
+    const LUT: [i8; 256] = gen_lut(&Self::ALPHABET);
+
+    /// encodes all of `input`, output is chars written. See [`base91_encoded_size`] for sizing `buf`
+    pub fn encode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        let input = input.as_ref();
+
+        let size = base91_encoded_size(input.len());
+        if size > buf.len() {
+            return Err(DecodeError::InvalidLength(size));
+        }
+
+        let mut encoder = Base91Encoder::default();
+        let written = encoder.update(input, buf)?;
+        Ok(written + encoder.finish(&mut buf[written..])?)
+    }
+
+    /// decodes all of `input`, output is bytes written.
+    /// See [`crate::util::base91_decoded_size`] for sizing `buf`.
+    ///
+    /// Like the reference decoder, chars outside the alphabet (such as line breaks in wrapped
+    /// output) are skipped rather than rejected.
+    pub fn decode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        let mut decoder = Base91Decoder::default();
+        let written = decoder.update(input, buf)?;
+        Ok(written + decoder.finish(&mut buf[written..])?)
+    }
+
+    /// Lookup the value for the current char index
+    fn lookup_ascii(ch: u8) -> Option<usize> {
+        match Self::LUT[ch as usize] {
+            -1 => None,
+            i => Some(i as usize),
+        }
+    }
+}
+
+/// streaming basE91 encoder, feed it with [`Base91Encoder::update`] then flush with [`Base91Encoder::finish`]
+#[derive(Debug, Default, Clone)]
+pub struct Base91Encoder {
+    queue: u32,
+    bits: u32,
+}
+
+impl Base91Encoder {
+    /// encodes as much of `input` as fills whole char pairs, output is chars written.
+    /// `buf` needs room for `base91_encoded_size(input.len())` chars.
+    ///
+    /// On error nothing is consumed, so the same input can be retried with a bigger `buf`
+    pub fn update<I: AsRef<[u8]>>(
+        &mut self,
+        input: I,
+        buf: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        // work on a copy so running out of room doesn't leave half the input in the queue
+        let mut next = self.clone();
+        let mut written = 0;
+
+        for &byte in input.as_ref() {
+            next.queue |= (byte as u32) << next.bits;
+            next.bits += 8;
+
+            if next.bits > 13 {
+                // 13 bits are enough unless they would land in the spare (89..=90) range
+                let mut value = next.queue & 8191;
+                if value > 88 {
+                    next.queue >>= 13;
+                    next.bits -= 13;
+                } else {
+                    value = next.queue & 16383;
+                    next.queue >>= 14;
+                    next.bits -= 14;
+                }
+
+                if written + 2 > buf.len() {
+                    return Err(DecodeError::InvalidLength(written + 2));
+                }
+                buf[written] = Base91::ALPHABET[(value % 91) as usize];
+                buf[written + 1] = Base91::ALPHABET[(value / 91) as usize];
+                written += 2;
+            }
+        }
+
+        *self = next;
+        Ok(written)
+    }
+
+    /// writes out any bits left over (at most 2 chars), output is chars written
+    pub fn finish(self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if self.bits == 0 {
+            return Ok(0);
+        }
+
+        let pair = self.bits > 7 || self.queue > 90;
+        let size = if pair { 2 } else { 1 };
+        if size > buf.len() {
+            return Err(DecodeError::InvalidLength(size));
+        }
+
+        buf[0] = Base91::ALPHABET[(self.queue % 91) as usize];
+        if pair {
+            buf[1] = Base91::ALPHABET[(self.queue / 91) as usize];
+        }
+
+        Ok(size)
+    }
+}
+
+/// streaming basE91 decoder, feed it with [`Base91Decoder::update`] then flush with [`Base91Decoder::finish`]
+#[derive(Debug, Default, Clone)]
+pub struct Base91Decoder {
+    queue: u32,
+    bits: u32,
+    /// first char of a pair still waiting on its second
+    pending: Option<u32>,
+}
+
+impl Base91Decoder {
+    /// decodes `input`, which may end half way through a char pair, output is bytes written.
+    /// `buf` needs room for `base91_decoded_size(input.len() + 1)` bytes.
+    /// Chars outside the alphabet are skipped, the same as the reference decoder.
+    ///
+    /// On error nothing is consumed, so the same input can be retried with a bigger `buf`
+    pub fn update<I: AsRef<[u8]>>(
+        &mut self,
+        input: I,
+        buf: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        // work on a copy so running out of room doesn't leave half the input in the queue
+        let mut next = self.clone();
+        let mut written = 0;
+
+        for &ch in input.as_ref() {
+            let digit = match Base91::lookup_ascii(ch) {
+                Some(digit) => digit as u32,
+                None => continue,
+            };
+
+            let low = match next.pending.take() {
+                None => {
+                    next.pending = Some(digit);
+                    continue;
+                }
+                Some(low) => low,
+            };
+
+            let value = low + digit * 91;
+            next.queue |= value << next.bits;
+            next.bits += if value & 8191 > 88 { 13 } else { 14 };
+
+            while next.bits > 7 {
+                if written >= buf.len() {
+                    return Err(DecodeError::InvalidLength(written + 1));
+                }
+                buf[written] = next.queue as u8;
+                written += 1;
+                next.queue >>= 8;
+                next.bits -= 8;
+            }
+        }
+
+        *self = next;
+        Ok(written)
+    }
+
+    /// writes out the final byte held by an unpaired char, output is bytes written
+    pub fn finish(self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        match self.pending {
+            None => Ok(0),
+            Some(low) => {
+                if buf.is_empty() {
+                    return Err(DecodeError::InvalidLength(1));
+                }
+                buf[0] = (self.queue | low << self.bits) as u8;
+                Ok(1)
+            }
+        }
+    }
+}
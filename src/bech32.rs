@@ -0,0 +1,487 @@
+use crate::{util::gen_lut, DecodeError};
+
+/// the 32 data chars, indexed by their 5 bit value
+pub const CHARSET: [u8; 32] = *b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+
+/// longest string BIP-173 allows, the `*_with_limit_mut` functions take a different one
+pub const MAX_LEN: usize = 90;
+
+/// chars in the checksum
+pub const CHECKSUM_LEN: usize = 6;
+
+const LUT: [i8; 256] = gen_lut(&CHARSET);
+
+const GENERATOR: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+/// which checksum constant the string uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    /// BIP-173
+    Bech32,
+    /// BIP-350
+    Bech32m,
+}
+
+impl Variant {
+    const fn constant(self) -> u32 {
+        match self {
+            Variant::Bech32 => 1,
+            Variant::Bech32m => 0x2bc830a3,
+        }
+    }
+}
+
+/// what [`decode_mut`] found besides the data
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Decoded<'a> {
+    /// human readable part, in the case it was written in
+    pub hrp: &'a str,
+    pub variant: Variant,
+    /// values (or bytes) written to the output buffer
+    pub len: usize,
+}
+
+/// encodes 5 bit `data` values (each `< 32`) under `hrp`, output is chars written.
+///
+/// ```rust
+/// use smol_base_x::bech32::{self, Variant};
+///
+/// let mut buf = [0u8; 90];
+/// let written = bech32::encode_mut("a", &[], Variant::Bech32, &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"a12uel5l");
+/// ```
+pub fn encode_mut(
+    hrp: &str,
+    data: &[u8],
+    variant: Variant,
+    buf: &mut [u8],
+) -> Result<usize, DecodeError> {
+    encode_with_limit_mut(hrp, data, variant, MAX_LEN, buf)
+}
+
+/// [`encode_mut`] with a custom length limit instead of [`MAX_LEN`], for formats like
+/// Nostr's NIP-19 that reuse the checksum on longer strings
+pub fn encode_with_limit_mut(
+    hrp: &str,
+    data: &[u8],
+    variant: Variant,
+    limit: usize,
+    buf: &mut [u8],
+) -> Result<usize, DecodeError> {
+    encode_with(hrp, data.len(), variant, limit, buf, |out| {
+        if data.iter().any(|&v| v >= 32) {
+            return Err(DecodeError::InvalidChar);
+        }
+        out.copy_from_slice(data);
+        Ok(())
+    })
+}
+
+/// decodes and verifies a bech32 or bech32m string, writing its 5 bit data values into `buf`.
+///
+/// ```rust
+/// use smol_base_x::bech32::{self, Variant};
+///
+/// let mut buf = [0u8; 90];
+/// let decoded = bech32::decode_mut("A1LQFN3A", &mut buf).unwrap();
+/// assert_eq!(decoded.hrp, "A");
+/// assert_eq!(decoded.variant, Variant::Bech32m);
+/// assert_eq!(decoded.len, 0);
+/// ```
+pub fn decode_mut<'a>(input: &'a str, buf: &mut [u8]) -> Result<Decoded<'a>, DecodeError> {
+    decode_with_limit_mut(input, MAX_LEN, buf)
+}
+
+/// [`decode_mut`] with a custom length limit instead of [`MAX_LEN`]
+pub fn decode_with_limit_mut<'a>(
+    input: &'a str,
+    limit: usize,
+    buf: &mut [u8],
+) -> Result<Decoded<'a>, DecodeError> {
+    let (hrp, variant, data) = parse(input, limit)?;
+
+    if data.len() > buf.len() {
+        return Err(DecodeError::InvalidLength(data.len()));
+    }
+    for (out, &ch) in buf.iter_mut().zip(data) {
+        *out = lookup(ch).unwrap_or(0);
+    }
+
+    Ok(Decoded {
+        hrp,
+        variant,
+        len: data.len(),
+    })
+}
+
+/// [`encode_mut`] for bytes, regrouping them into 5 bit values first
+pub fn encode_bytes_mut(
+    hrp: &str,
+    bytes: &[u8],
+    variant: Variant,
+    buf: &mut [u8],
+) -> Result<usize, DecodeError> {
+    encode_bytes_with_limit_mut(hrp, bytes, variant, MAX_LEN, buf)
+}
+
+/// [`encode_bytes_mut`] with a custom length limit instead of [`MAX_LEN`].
+///
+/// ```rust
+/// use smol_base_x::bech32::{self, Variant};
+///
+/// // a NIP-19 nprofile: a pubkey and a relay, too long for BIP-173
+/// let mut tlv = [0u8; 47];
+/// tlv[..2].copy_from_slice(&[0, 32]);
+/// tlv[34..36].copy_from_slice(&[1, 11]);
+/// tlv[36..].copy_from_slice(b"wss://a.com");
+///
+/// let mut buf = [0u8; 128];
+/// assert!(bech32::encode_bytes_mut("nprofile", &tlv, Variant::Bech32, &mut buf).is_err());
+/// let written =
+///     bech32::encode_bytes_with_limit_mut("nprofile", &tlv, Variant::Bech32, 5000, &mut buf)
+///         .unwrap();
+/// assert_eq!(written, 91);
+/// ```
+pub fn encode_bytes_with_limit_mut(
+    hrp: &str,
+    bytes: &[u8],
+    variant: Variant,
+    limit: usize,
+    buf: &mut [u8],
+) -> Result<usize, DecodeError> {
+    let len = (bytes.len() * 8).div_ceil(5);
+    encode_with(hrp, len, variant, limit, buf, |out| {
+        convert_bits(bytes, 8, 5, true, out).map(|_| ())
+    })
+}
+
+/// [`decode_mut`] for bytes, the 5 bit values must regroup into whole bytes with zero padding
+pub fn decode_bytes_mut<'a>(input: &'a str, buf: &mut [u8]) -> Result<Decoded<'a>, DecodeError> {
+    decode_bytes_with_limit_mut(input, MAX_LEN, buf)
+}
+
+/// [`decode_bytes_mut`] with a custom length limit instead of [`MAX_LEN`]
+pub fn decode_bytes_with_limit_mut<'a>(
+    input: &'a str,
+    limit: usize,
+    buf: &mut [u8],
+) -> Result<Decoded<'a>, DecodeError> {
+    let (hrp, variant, data) = parse(input, limit)?;
+    let values = data.iter().map(|&ch| lookup(ch).unwrap_or(0));
+
+    Ok(Decoded {
+        hrp,
+        variant,
+        len: regroup(values, 5, 8, false, buf)?,
+    })
+}
+
+/// encodes a segwit address: witness version (0 to 16) and program.
+/// Version 0 uses bech32, later versions bech32m (BIP-350).
+///
+/// ```rust
+/// use smol_base_x::bech32;
+///
+/// let program = [
+///     0x75, 0x1e, 0x76, 0xe8, 0x19, 0x91, 0x96, 0xd4, 0x54, 0x94,
+///     0x1c, 0x45, 0xd1, 0xb3, 0xa3, 0x23, 0xf1, 0x43, 0x3b, 0xd6,
+/// ];
+/// let mut buf = [0u8; 90];
+/// let written = bech32::encode_segwit("bc", 0, &program, &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4");
+/// ```
+pub fn encode_segwit(
+    hrp: &str,
+    version: u8,
+    program: &[u8],
+    buf: &mut [u8],
+) -> Result<usize, DecodeError> {
+    check_witness(version, program.len())?;
+
+    let mut data = [0u8; MAX_LEN];
+    data[0] = version;
+    let len = convert_bits(program, 8, 5, true, &mut data[1..])?;
+
+    let variant = if version == 0 {
+        Variant::Bech32
+    } else {
+        Variant::Bech32m
+    };
+    encode_mut(hrp, &data[..len + 1], variant, buf)
+}
+
+/// decodes a segwit address into its program, output is `(decoded, witness version)`.
+/// `decoded.len` is the program length.
+pub fn decode_segwit<'a>(
+    input: &'a str,
+    program: &mut [u8],
+) -> Result<(Decoded<'a>, u8), DecodeError> {
+    let mut data = [0u8; MAX_LEN];
+    let mut decoded = decode_mut(input, &mut data)?;
+
+    let (&version, rest) = data[..decoded.len]
+        .split_first()
+        .ok_or(DecodeError::InvalidLength(1))?;
+
+    let expected = if version == 0 {
+        Variant::Bech32
+    } else {
+        Variant::Bech32m
+    };
+    if decoded.variant != expected {
+        return Err(DecodeError::ChecksumMismatch);
+    }
+
+    decoded.len = convert_bits(rest, 5, 8, false, program)?;
+    check_witness(version, decoded.len)?;
+
+    Ok((decoded, version))
+}
+
+/// position of the first char that makes `input` invalid: either a char outside of [`CHARSET`],
+/// or a single data char that, once replaced, gives a valid checksum for either variant.
+///
+/// `None` when `input` is valid or the error can't be pinned down to one char.
+pub fn locate_error(input: &str) -> Option<usize> {
+    let bytes = input.as_bytes();
+    let separator = bytes.iter().rposition(|&ch| ch == b'1')?;
+    let (hrp, data) = (&bytes[..separator], &bytes[separator + 1..]);
+
+    if let Some(i) = data.iter().position(|&ch| lookup(ch).is_none()) {
+        return Some(separator + 1 + i);
+    }
+
+    let valid = |values: &mut dyn Iterator<Item = u8>| {
+        let residue = polymod(expand_hrp(hrp).chain(values));
+        residue == Variant::Bech32.constant() || residue == Variant::Bech32m.constant()
+    };
+
+    if valid(&mut data.iter().map(|&ch| lookup(ch).unwrap_or(0))) {
+        return None;
+    }
+
+    for at in 0..data.len() {
+        let original = lookup(data[at]).unwrap_or(0);
+        for replacement in (0..32).filter(|&v| v != original) {
+            let mut values = data.iter().enumerate().map(|(i, &ch)| {
+                if i == at {
+                    replacement
+                } else {
+                    lookup(ch).unwrap_or(0)
+                }
+            });
+            if valid(&mut values) {
+                return Some(separator + 1 + at);
+            }
+        }
+    }
+
+    None
+}
+
+/// regroups `from` bit values into `to` bit values, output is values written.
+///
+/// With `pad` a partial trailing group is zero filled,
+/// otherwise left over bits must be zero and fewer than `from`.
+pub fn convert_bits(
+    input: &[u8],
+    from: u32,
+    to: u32,
+    pad: bool,
+    out: &mut [u8],
+) -> Result<usize, DecodeError> {
+    regroup(input.iter().copied(), from, to, pad, out)
+}
+
+fn regroup(
+    input: impl Iterator<Item = u8>,
+    from: u32,
+    to: u32,
+    pad: bool,
+    out: &mut [u8],
+) -> Result<usize, DecodeError> {
+    let max = (1u32 << to) - 1;
+    let max_acc = (1u32 << (from + to - 1)) - 1;
+
+    let mut acc = 0u32;
+    let mut bits = 0;
+    let mut written = 0;
+
+    for value in input {
+        if (value as u32) >> from != 0 {
+            return Err(DecodeError::InvalidChar);
+        }
+        acc = ((acc << from) | value as u32) & max_acc;
+        bits += from;
+        while bits >= to {
+            bits -= to;
+            if written >= out.len() {
+                return Err(DecodeError::InvalidLength(written + 1));
+            }
+            out[written] = ((acc >> bits) & max) as u8;
+            written += 1;
+        }
+    }
+
+    if pad {
+        if bits > 0 {
+            if written >= out.len() {
+                return Err(DecodeError::InvalidLength(written + 1));
+            }
+            out[written] = ((acc << (to - bits)) & max) as u8;
+            written += 1;
+        }
+    } else if bits >= from {
+        return Err(DecodeError::InvalidPadding);
+    } else if (acc << (to - bits)) & max != 0 {
+        return Err(DecodeError::NonCanonical);
+    }
+
+    Ok(written)
+}
+
+/// writes `hrp`, the separator and the checksum around the `len` values `fill` writes
+fn encode_with(
+    hrp: &str,
+    len: usize,
+    variant: Variant,
+    limit: usize,
+    buf: &mut [u8],
+    fill: impl FnOnce(&mut [u8]) -> Result<(), DecodeError>,
+) -> Result<usize, DecodeError> {
+    let hrp = hrp.as_bytes();
+    check_hrp(hrp)?;
+
+    let size = hrp.len() + 1 + len + CHECKSUM_LEN;
+    if size > limit {
+        return Err(DecodeError::InvalidLength(limit));
+    }
+    if size > buf.len() {
+        return Err(DecodeError::InvalidLength(size));
+    }
+
+    let data_start = hrp.len() + 1;
+    let checksum_start = data_start + len;
+    let data = &mut buf[data_start..checksum_start];
+    fill(data)?;
+
+    let checksum = polymod(
+        expand_hrp(hrp)
+            .chain(data.iter().copied())
+            .chain([0; CHECKSUM_LEN]),
+    ) ^ variant.constant();
+
+    for v in data.iter_mut() {
+        *v = CHARSET[*v as usize];
+    }
+    // output is always lower case
+    for (out, &ch) in buf.iter_mut().zip(hrp) {
+        *out = ch.to_ascii_lowercase();
+    }
+    buf[hrp.len()] = b'1';
+    for (i, out) in buf[checksum_start..size].iter_mut().enumerate() {
+        *out = CHARSET[((checksum >> (5 * (5 - i))) & 31) as usize];
+    }
+
+    Ok(size)
+}
+
+/// checks `input` up to and including its checksum, output is `(hrp, variant, data chars)`
+fn parse(input: &str, limit: usize) -> Result<(&str, Variant, &[u8]), DecodeError> {
+    let bytes = input.as_bytes();
+
+    if bytes.len() > limit {
+        return Err(DecodeError::InvalidLength(limit));
+    }
+    if bytes.iter().any(|&ch| !(33..=126).contains(&ch)) {
+        return Err(DecodeError::InvalidChar);
+    }
+    if bytes.iter().any(u8::is_ascii_lowercase) && bytes.iter().any(u8::is_ascii_uppercase) {
+        return Err(DecodeError::MixedCase);
+    }
+
+    let separator = bytes
+        .iter()
+        .rposition(|&ch| ch == b'1')
+        .ok_or(DecodeError::InvalidChar)?;
+    let (hrp, data) = (&bytes[..separator], &bytes[separator + 1..]);
+
+    if hrp.is_empty() {
+        return Err(DecodeError::InvalidLength(1));
+    }
+    if data.len() < CHECKSUM_LEN {
+        return Err(DecodeError::InvalidLength(separator + 1 + CHECKSUM_LEN));
+    }
+    if data.iter().any(|&ch| lookup(ch).is_none()) {
+        return Err(DecodeError::InvalidChar);
+    }
+
+    let residue = polymod(expand_hrp(hrp).chain(data.iter().map(|&ch| lookup(ch).unwrap_or(0))));
+    let variant = match residue {
+        r if r == Variant::Bech32.constant() => Variant::Bech32,
+        r if r == Variant::Bech32m.constant() => Variant::Bech32m,
+        _ => return Err(DecodeError::ChecksumMismatch),
+    };
+
+    Ok((
+        &input[..separator],
+        variant,
+        &data[..data.len() - CHECKSUM_LEN],
+    ))
+}
+
+fn lookup(ch: u8) -> Option<u8> {
+    match LUT[ch.to_ascii_lowercase() as usize] {
+        -1 => None,
+        i => Some(i as u8),
+    }
+}
+
+fn check_hrp(hrp: &[u8]) -> Result<(), DecodeError> {
+    if hrp.is_empty() || hrp.len() > 83 {
+        return Err(DecodeError::InvalidLength(1));
+    }
+    if hrp.iter().any(|&ch| !(33..=126).contains(&ch)) {
+        return Err(DecodeError::InvalidChar);
+    }
+    if hrp.iter().any(u8::is_ascii_lowercase) && hrp.iter().any(u8::is_ascii_uppercase) {
+        return Err(DecodeError::MixedCase);
+    }
+    Ok(())
+}
+
+/// BIP-141 program lengths
+fn check_witness(version: u8, program_len: usize) -> Result<(), DecodeError> {
+    if version > 16 {
+        return Err(DecodeError::InvalidChar);
+    }
+    if !(2..=40).contains(&program_len) || (version == 0 && program_len != 20 && program_len != 32)
+    {
+        return Err(DecodeError::InvalidLength(program_len));
+    }
+    Ok(())
+}
+
+/// high bits of each hrp char, a zero, then the low bits of each char
+fn expand_hrp(hrp: &[u8]) -> impl Iterator<Item = u8> + '_ {
+    let lower = hrp.iter().map(u8::to_ascii_lowercase);
+    lower
+        .clone()
+        .map(|ch| ch >> 5)
+        .chain([0])
+        .chain(lower.map(|ch| ch & 31))
+}
+
+fn polymod(values: impl Iterator<Item = u8>) -> u32 {
+    let mut chk = 1u32;
+    for value in values {
+        let top = chk >> 25;
+        chk = (chk & 0x1ffffff) << 5 ^ value as u32;
+        for (i, generator) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= generator;
+            }
+        }
+    }
+    chk
+}
@@ -0,0 +1,168 @@
+use crate::{Base, DecodeError};
+
+/// A single check symbol computed over the *encoded* symbols (not the bytes),
+/// appended on encode and verified on decode.
+///
+/// ```rust
+/// use smol_base_x::{check_digit::{CheckDigit, Damm}, Base10};
+///
+/// let mut buf = [0u8; 8];
+/// let written = Damm::encode_mut::<Base10, _>([0x02, 0x3c], &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"5724");
+///
+/// assert!(Damm::validate::<Base10>(b"5724").is_ok());
+/// assert!(Damm::validate::<Base10>(b"5274").is_err());
+/// ```
+pub trait CheckDigit<const BASE: usize> {
+    /// alphabet index of the check symbol for `encoded`
+    fn compute<B: Base<BASE>>(encoded: &[u8]) -> Result<usize, DecodeError>;
+
+    /// checks the last symbol of `encoded` is the check symbol for the rest
+    fn validate<B: Base<BASE>>(encoded: &[u8]) -> Result<(), DecodeError> {
+        let (&check, encoded) = encoded.split_last().ok_or(DecodeError::InvalidLength(1))?;
+
+        let check = B::lookup_ascii(check).ok_or(DecodeError::InvalidChar)?;
+        if Self::compute::<B>(encoded)? != check {
+            return Err(DecodeError::ChecksumMismatch);
+        }
+
+        Ok(())
+    }
+
+    /// [`Base::encode_mut`] followed by the check symbol
+    fn encode_mut<B: Base<BASE>, I: AsRef<[u8]>>(
+        input: I,
+        buf: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        let written = B::encode_mut(input, buf)?;
+
+        if written == buf.len() {
+            return Err(DecodeError::InvalidLength(written + 1));
+        }
+        buf[written] = B::ALPHABET[Self::compute::<B>(&buf[..written])?];
+
+        Ok(written + 1)
+    }
+
+    /// validates the check symbol then [`Base::decode_mut`]s the rest.
+    /// Leading and trailing spaces are ignored the same way `decode_mut` does.
+    fn decode_mut<B: Base<BASE>, I: AsRef<[u8]>>(
+        input: I,
+        buf: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        let input = input.as_ref().trim_ascii();
+
+        Self::validate::<B>(input)?;
+        B::decode_mut(&input[..input.len() - 1], buf)
+    }
+}
+
+/// Luhn mod N over the alphabet indices, for alphabets of an even size.
+///
+/// Catches every single symbol error and most (but not all) adjacent transpositions,
+/// for `Base10` this is the plain Luhn algorithm used for card numbers.
+/// Doubling only maps every symbol to a distinct value when the base is even,
+/// so odd bases (which would miss some substitutions) are rejected with
+/// [`DecodeError::UnsupportedBase`].
+///
+/// ```rust
+/// use smol_base_x::{check_digit::{CheckDigit, LuhnModN}, Base11, DecodeError};
+///
+/// assert!(matches!(LuhnModN::compute::<Base11>(b"51"), Err(DecodeError::UnsupportedBase)));
+/// ```
+#[derive(Debug, Default)]
+pub struct LuhnModN;
+
+impl<const BASE: usize> CheckDigit<BASE> for LuhnModN {
+    fn compute<B: Base<BASE>>(encoded: &[u8]) -> Result<usize, DecodeError> {
+        if !BASE.is_multiple_of(2) {
+            return Err(DecodeError::UnsupportedBase);
+        }
+
+        let mut factor = 2;
+        let mut sum = 0;
+
+        for &ch in encoded.iter().rev() {
+            let code_point = B::lookup_ascii(ch).ok_or(DecodeError::InvalidChar)?;
+
+            let addend = factor * code_point;
+            sum += addend / BASE + addend % BASE;
+            factor = if factor == 2 { 1 } else { 2 };
+        }
+
+        Ok((BASE - sum % BASE) % BASE)
+    }
+}
+
+/// Damm algorithm, catches all single digit errors and adjacent transpositions. `Base10` only.
+#[derive(Debug, Default)]
+pub struct Damm;
+
+impl CheckDigit<10> for Damm {
+    fn compute<B: Base<10>>(encoded: &[u8]) -> Result<usize, DecodeError> {
+        // weakly totally anti-symmetric quasigroup of order 10
+        const TABLE: [[u8; 10]; 10] = [
+            [0, 3, 1, 7, 5, 9, 8, 6, 4, 2],
+            [7, 0, 9, 2, 1, 5, 4, 8, 6, 3],
+            [4, 2, 0, 6, 8, 7, 1, 3, 5, 9],
+            [1, 7, 5, 0, 9, 8, 3, 4, 2, 6],
+            [6, 1, 2, 3, 0, 4, 5, 9, 7, 8],
+            [3, 6, 7, 4, 2, 0, 9, 5, 8, 1],
+            [5, 8, 6, 9, 7, 2, 0, 1, 3, 4],
+            [8, 9, 4, 5, 3, 6, 2, 0, 1, 7],
+            [9, 4, 3, 8, 6, 1, 7, 2, 0, 5],
+            [2, 5, 8, 1, 4, 3, 6, 7, 9, 0],
+        ];
+
+        let mut interim = 0;
+        for &ch in encoded {
+            let digit = B::lookup_ascii(ch).ok_or(DecodeError::InvalidChar)?;
+            interim = TABLE[interim][digit] as usize;
+        }
+
+        Ok(interim)
+    }
+}
+
+/// Verhoeff algorithm, catches all single digit errors and adjacent transpositions. `Base10` only.
+#[derive(Debug, Default)]
+pub struct Verhoeff;
+
+impl CheckDigit<10> for Verhoeff {
+    fn compute<B: Base<10>>(encoded: &[u8]) -> Result<usize, DecodeError> {
+        // multiplication table of the dihedral group D5
+        const D: [[u8; 10]; 10] = [
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+            [1, 2, 3, 4, 0, 6, 7, 8, 9, 5],
+            [2, 3, 4, 0, 1, 7, 8, 9, 5, 6],
+            [3, 4, 0, 1, 2, 8, 9, 5, 6, 7],
+            [4, 0, 1, 2, 3, 9, 5, 6, 7, 8],
+            [5, 9, 8, 7, 6, 0, 4, 3, 2, 1],
+            [6, 5, 9, 8, 7, 1, 0, 4, 3, 2],
+            [7, 6, 5, 9, 8, 2, 1, 0, 4, 3],
+            [8, 7, 6, 5, 9, 3, 2, 1, 0, 4],
+            [9, 8, 7, 6, 5, 4, 3, 2, 1, 0],
+        ];
+        // position dependent permutations
+        const P: [[u8; 10]; 8] = [
+            [0, 1, 2, 3, 4, 5, 6, 7, 8, 9],
+            [1, 5, 7, 6, 2, 8, 3, 0, 9, 4],
+            [5, 8, 0, 3, 7, 9, 6, 1, 4, 2],
+            [8, 9, 1, 6, 0, 4, 3, 5, 2, 7],
+            [9, 4, 5, 3, 1, 2, 7, 6, 8, 0],
+            [4, 2, 8, 6, 5, 7, 3, 9, 0, 1],
+            [2, 7, 9, 3, 8, 0, 6, 4, 1, 5],
+            [7, 0, 4, 6, 9, 1, 3, 2, 5, 8],
+        ];
+        const INV: [u8; 10] = [0, 4, 3, 2, 1, 5, 6, 7, 8, 9];
+
+        let mut c = 0;
+        // position 0 is reserved for the check digit itself
+        for (i, &ch) in encoded.iter().rev().enumerate() {
+            let digit = B::lookup_ascii(ch).ok_or(DecodeError::InvalidChar)?;
+            c = D[c][P[(i + 1) % 8][digit] as usize] as usize;
+        }
+
+        Ok(INV[c] as usize)
+    }
+}
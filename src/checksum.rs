@@ -0,0 +1,173 @@
+use core::marker::PhantomData;
+
+use crate::{
+    base::{encode_bytes, Base},
+    sha256::double_sha256,
+    DecodeError,
+};
+
+/// a checksum appended to the payload bytes before encoding
+pub trait Checksum {
+    /// length of the checksum in bytes
+    const LEN: usize;
+
+    /// usually `[u8; Self::LEN]`
+    type Output: AsRef<[u8]>;
+
+    fn checksum(data: &[u8]) -> Self::Output;
+}
+
+/// Wraps any [`Base`] so encoding appends `C` to the input and decoding verifies and strips it.
+///
+/// ```rust
+/// use smol_base_x::{checksum::{Checked, Crc32}, Base62};
+///
+/// let mut buf = [0u8; 32];
+/// let written = Checked::<Base62, Crc32>::encode_mut("abc", &mut buf).unwrap();
+///
+/// let mut decoded = [0u8; 32];
+/// let len = Checked::<Base62, Crc32>::decode_mut(&buf[..written], &mut decoded).unwrap();
+/// assert_eq!(&decoded[..len], b"abc");
+/// ```
+#[derive(Debug, Default)]
+pub struct Checked<B, C>(PhantomData<(B, C)>);
+
+impl<B, C: Checksum> Checked<B, C> {
+    /// encodes `input || checksum`, see [`Base::encode_mut`]
+    pub fn encode_mut<const BASE: usize, I: AsRef<[u8]>>(
+        input: I,
+        buf: &mut [u8],
+    ) -> Result<usize, DecodeError>
+    where
+        B: Base<BASE>,
+    {
+        let input = input.as_ref();
+        let checksum = C::checksum(input);
+        let checksum = checksum.as_ref();
+
+        let bytes = input.iter().chain(checksum).copied();
+        encode_bytes(&B::ALPHABET, bytes, input.len() + checksum.len(), buf)
+    }
+
+    /// decodes into `buf` and verifies the trailing checksum, returning the payload bytes written.
+    ///
+    /// `buf` needs room for the checksum as well, see [`Base::decode_mut`]
+    pub fn decode_mut<const BASE: usize, I: AsRef<[u8]>>(
+        input: I,
+        buf: &mut [u8],
+    ) -> Result<usize, DecodeError>
+    where
+        B: Base<BASE>,
+    {
+        let written = B::decode_mut(input, buf)?;
+
+        if written < C::LEN {
+            return Err(DecodeError::InvalidLength(C::LEN));
+        }
+
+        let (data, checksum) = buf[..written].split_at(written - C::LEN);
+        if C::checksum(data).as_ref() != checksum {
+            return Err(DecodeError::ChecksumMismatch);
+        }
+
+        Ok(data.len())
+    }
+}
+
+/// CRC-16/XMODEM (poly `0x1021`, init `0`), big-endian
+#[derive(Debug, Default)]
+pub struct Crc16Xmodem;
+
+impl Checksum for Crc16Xmodem {
+    const LEN: usize = 2;
+    type Output = [u8; 2];
+
+    fn checksum(data: &[u8]) -> Self::Output {
+        const TABLE: [u16; 256] = gen_crc16_table(0x1021);
+
+        let mut crc = 0u16;
+        for &byte in data {
+            crc = (crc << 8) ^ TABLE[((crc >> 8) as u8 ^ byte) as usize];
+        }
+        crc.to_be_bytes()
+    }
+}
+
+/// CRC-32 as used by zlib/ethernet (reflected poly `0xEDB88320`), big-endian
+#[derive(Debug, Default)]
+pub struct Crc32;
+
+impl Checksum for Crc32 {
+    const LEN: usize = 4;
+    type Output = [u8; 4];
+
+    fn checksum(data: &[u8]) -> Self::Output {
+        const TABLE: [u32; 256] = gen_crc32_table(0xEDB88320);
+
+        let mut crc = !0u32;
+        for &byte in data {
+            crc = (crc >> 8) ^ TABLE[(crc as u8 ^ byte) as usize];
+        }
+        (!crc).to_be_bytes()
+    }
+}
+
+/// first 4 bytes of `SHA256(SHA256(data))`, the Base58Check checksum
+#[derive(Debug, Default)]
+pub struct DoubleSha256;
+
+impl Checksum for DoubleSha256 {
+    const LEN: usize = 4;
+    type Output = [u8; 4];
+
+    fn checksum(data: &[u8]) -> Self::Output {
+        let hash = double_sha256(&[data]);
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+}
+
+/// MSB-first table for CRC-16 with the given polynomial
+const fn gen_crc16_table(poly: u16) -> [u16; 256] {
+    let mut table = [0u16; 256];
+
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = (i as u16) << 8;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ poly
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+
+    table
+}
+
+/// LSB-first (reflected) table for CRC-32 with the given reversed polynomial
+const fn gen_crc32_table(poly: u32) -> [u32; 256] {
+    let mut table = [0u32; 256];
+
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 != 0 {
+                (crc >> 1) ^ poly
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+
+    table
+}
@@ -0,0 +1,174 @@
+use crate::{base::encode_bytes, Base, Base58Btc, DecodeError};
+
+/// multicodec code of sha2-256, the only hash a CIDv0 can use
+pub const SHA2_256: u64 = 0x12;
+
+/// digest bytes a [`Multihash`] can hold, enough for sha2-512 and blake2b-512
+pub const MAX_DIGEST_LEN: usize = 64;
+
+/// bytes in a CIDv0: the 2 byte header plus a 32 byte sha2-256 digest
+pub const V0_LEN: usize = 34;
+
+/// a hash function code, the digest length and the digest itself
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Multihash {
+    pub code: u64,
+    size: u8,
+    digest: [u8; MAX_DIGEST_LEN],
+}
+
+impl Multihash {
+    /// errors with [`DecodeError::InvalidLength`] when `digest` is longer than [`MAX_DIGEST_LEN`],
+    /// or [`DecodeError::Overflow`] when `code` doesn't fit a 9 byte varint
+    pub fn new(code: u64, digest: &[u8]) -> Result<Self, DecodeError> {
+        if code >> (7 * MAX_VARINT_LEN) != 0 {
+            return Err(DecodeError::Overflow);
+        }
+        if digest.len() > MAX_DIGEST_LEN {
+            return Err(DecodeError::InvalidLength(MAX_DIGEST_LEN));
+        }
+
+        let mut bytes = [0u8; MAX_DIGEST_LEN];
+        bytes[..digest.len()].copy_from_slice(digest);
+
+        Ok(Multihash {
+            code,
+            size: digest.len() as u8,
+            digest: bytes,
+        })
+    }
+
+    pub fn size(&self) -> usize {
+        self.size as usize
+    }
+
+    pub fn digest(&self) -> &[u8] {
+        &self.digest[..self.size()]
+    }
+
+    /// parses `<varint code><varint size><digest>` from the start of `input`.
+    ///
+    /// output is `(multihash, bytes read)`
+    pub fn read(input: &[u8]) -> Result<(Self, usize), DecodeError> {
+        let (code, mut read) = read_varint(input)?;
+        let (size, size_len) = read_varint(&input[read..])?;
+        read += size_len;
+
+        let size = size as usize;
+        if size > MAX_DIGEST_LEN {
+            return Err(DecodeError::InvalidLength(MAX_DIGEST_LEN));
+        }
+        if read + size > input.len() {
+            return Err(DecodeError::InvalidLength(read + size));
+        }
+
+        Ok((Self::new(code, &input[read..read + size])?, read + size))
+    }
+
+    /// writes `<varint code><varint size><digest>`, output is bytes written
+    pub fn write(&self, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        let mut header = [0u8; 2 * MAX_VARINT_LEN];
+        let mut len = write_varint(self.code, &mut header);
+        len += write_varint(self.size as u64, &mut header[len..]);
+
+        let total = len + self.size();
+        if total > buf.len() {
+            return Err(DecodeError::InvalidLength(total));
+        }
+
+        buf[..len].copy_from_slice(&header[..len]);
+        buf[len..total].copy_from_slice(self.digest());
+
+        Ok(total)
+    }
+}
+
+/// decodes a `Qm...` CIDv0, which is a bare sha2-256 multihash in [`Base58Btc`].
+///
+/// ```rust
+/// use smol_base_x::cid;
+///
+/// let hash = cid::decode_v0("QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n").unwrap();
+/// assert_eq!(hash.code, cid::SHA2_256);
+/// assert_eq!(hash.digest()[..4], [0xe3, 0xb0, 0xc4, 0x42]);
+///
+/// let mut buf = [0u8; 64];
+/// let written = cid::encode_v0(&hash, &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n");
+/// ```
+pub fn decode_v0<I: AsRef<[u8]>>(input: I) -> Result<Multihash, DecodeError> {
+    let mut buf = [0u8; V0_LEN];
+    let written = Base58Btc::decode_mut(input, &mut buf)?;
+
+    if written != V0_LEN {
+        return Err(DecodeError::InvalidLength(V0_LEN));
+    }
+
+    let (hash, _) = Multihash::read(&buf)?;
+    check_v0(&hash)?;
+
+    Ok(hash)
+}
+
+/// encodes a sha2-256 multihash as a CIDv0, output is chars written (always 46)
+pub fn encode_v0(hash: &Multihash, buf: &mut [u8]) -> Result<usize, DecodeError> {
+    check_v0(hash)?;
+
+    let header = [SHA2_256 as u8, hash.size];
+    let bytes = header.iter().chain(hash.digest()).copied();
+    encode_bytes(&Base58Btc::ALPHABET, bytes, V0_LEN, buf)
+}
+
+/// CIDv0 is only ever a 32 byte sha2-256
+fn check_v0(hash: &Multihash) -> Result<(), DecodeError> {
+    if hash.code != SHA2_256 {
+        return Err(DecodeError::UnknownPrefix);
+    }
+    if hash.size() != 32 {
+        return Err(DecodeError::InvalidLength(V0_LEN));
+    }
+    Ok(())
+}
+
+/// multiformats caps varints at 9 bytes (63 bits)
+const MAX_VARINT_LEN: usize = 9;
+
+/// unsigned LEB128, output is `(value, bytes read)`.
+///
+/// Varints with redundant trailing zero groups are rejected as [`DecodeError::NonCanonical`].
+fn read_varint(input: &[u8]) -> Result<(u64, usize), DecodeError> {
+    let mut value = 0u64;
+
+    for (i, &byte) in input.iter().enumerate() {
+        if i == MAX_VARINT_LEN {
+            return Err(DecodeError::Overflow);
+        }
+
+        value |= ((byte & 0x7F) as u64) << (7 * i);
+
+        if byte & 0x80 == 0 {
+            if byte == 0 && i > 0 {
+                return Err(DecodeError::NonCanonical);
+            }
+            return Ok((value, i + 1));
+        }
+    }
+
+    Err(DecodeError::InvalidLength(input.len() + 1))
+}
+
+/// `buf` must fit [`MAX_VARINT_LEN`] bytes, output is bytes written
+fn write_varint(mut value: u64, buf: &mut [u8]) -> usize {
+    let mut written = 0;
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+
+        if value == 0 {
+            buf[written] = byte;
+            return written + 1;
+        }
+        buf[written] = byte | 0x80;
+        written += 1;
+    }
+}
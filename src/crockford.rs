@@ -0,0 +1,124 @@
+use crate::{base_impl::crockford_lut, Base, Base32Crockford, DecodeError};
+
+/// the 32 symbols plus `*~$=U` for check values 32 through 36
+pub const CHECK_ALPHABET: [u8; 37] = *b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
+const CHECK_LUT: [i8; 256] = crockford_lut(&CHECK_ALPHABET);
+
+/// most chars a `u64` encodes to, add one for the check symbol
+pub const U64_LEN: usize = 13;
+
+/// encodes `value` as a number, with no leading zeros (0 is `"0"`). output is chars written
+///
+/// ```rust
+/// use smol_base_x::crockford;
+///
+/// let mut buf = [0u8; crockford::U64_LEN];
+/// let written = crockford::encode_u64(1234, &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"16J");
+/// assert_eq!(crockford::decode_u64("1-6j").unwrap(), 1234);
+/// ```
+pub fn encode_u64(value: u64, buf: &mut [u8]) -> Result<usize, DecodeError> {
+    let encoded = Base32Crockford::encode_u64(value);
+
+    if encoded.len() > buf.len() {
+        return Err(DecodeError::InvalidLength(encoded.len()));
+    }
+    buf[..encoded.len()].copy_from_slice(encoded.as_bytes());
+
+    Ok(encoded.len())
+}
+
+/// decodes a number, case-insensitive with aliases and hyphens like [`Base32Crockford`]
+pub fn decode_u64<I: AsRef<[u8]>>(input: I) -> Result<u64, DecodeError> {
+    let input = input.as_ref();
+
+    if !input.iter().any(|&ch| ch != b'-') {
+        return Err(DecodeError::InvalidLength(1));
+    }
+
+    let mut value = 0u64;
+    for &ch in input.iter().filter(|&&ch| ch != b'-') {
+        let digit = Base32Crockford::lookup_ascii(ch).ok_or(DecodeError::InvalidChar)?;
+        value = value
+            .checked_mul(32)
+            .and_then(|value| value.checked_add(digit as u64))
+            .ok_or(DecodeError::Overflow)?;
+    }
+
+    Ok(value)
+}
+
+/// [`encode_u64`] followed by the `value mod 37` check symbol
+///
+/// ```rust
+/// use smol_base_x::crockford;
+///
+/// let mut buf = [0u8; crockford::U64_LEN + 1];
+/// let written = crockford::encode_u64_check(1234, &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"16JD");
+/// assert_eq!(crockford::decode_u64_check("16-jd").unwrap(), 1234);
+/// ```
+pub fn encode_u64_check(value: u64, buf: &mut [u8]) -> Result<usize, DecodeError> {
+    let written = encode_u64(value, buf)?;
+    if written == buf.len() {
+        return Err(DecodeError::InvalidLength(written + 1));
+    }
+
+    buf[written] = CHECK_ALPHABET[(value % 37) as usize];
+    Ok(written + 1)
+}
+
+/// [`decode_u64`] verifying the trailing check symbol
+pub fn decode_u64_check<I: AsRef<[u8]>>(input: I) -> Result<u64, DecodeError> {
+    let (input, check) = split_check(input.as_ref())?;
+
+    let value = decode_u64(input)?;
+    if (value % 37) as usize != check {
+        return Err(DecodeError::ChecksumMismatch);
+    }
+
+    Ok(value)
+}
+
+/// [`Base::encode_mut`] of the bytes as one number, followed by its `mod 37` check symbol
+pub fn encode_check_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+    let input = input.as_ref();
+
+    let written = Base32Crockford::encode_mut(input, buf)?;
+    if written == buf.len() {
+        return Err(DecodeError::InvalidLength(written + 1));
+    }
+
+    buf[written] = CHECK_ALPHABET[bytes_mod_37(input)];
+    Ok(written + 1)
+}
+
+/// [`Base::decode_mut`] verifying the trailing check symbol, output is bytes written
+pub fn decode_check_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+    let (input, check) = split_check(input.as_ref().trim_ascii())?;
+
+    let written = Base32Crockford::decode_mut(input, buf)?;
+    if bytes_mod_37(&buf[..written]) != check {
+        return Err(DecodeError::ChecksumMismatch);
+    }
+
+    Ok(written)
+}
+
+/// splits off the last symbol as a check value
+fn split_check(input: &[u8]) -> Result<(&[u8], usize), DecodeError> {
+    let (&check, input) = input.split_last().ok_or(DecodeError::InvalidLength(2))?;
+
+    match CHECK_LUT[check as usize] {
+        -1 => Err(DecodeError::InvalidChar),
+        i => Ok((input, i as usize)),
+    }
+}
+
+/// big-endian bytes as a number, mod 37
+fn bytes_mod_37(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .fold(0, |acc, &byte| (acc * 256 + byte as usize) % 37)
+}
@@ -0,0 +1,261 @@
+use crate::{
+    base::{decode_fixed, fixed_digits},
+    Base, DecodeError,
+};
+
+#[cfg(feature = "aes")]
+pub use crate::aes::Aes128;
+
+/// longest string [`Ff1`] accepts, which keeps every intermediate value on the stack
+pub const MAX_LEN: usize = 128;
+
+/// the spec requires `BASE^len` to be at least this many values
+pub const MIN_DOMAIN: u64 = 1_000_000;
+
+const ROUNDS: u8 = 10;
+
+/// a 128 bit block cipher, FF1 only ever runs it forwards
+pub trait BlockCipher {
+    /// encrypts one block in place
+    fn encrypt_block(&self, block: &mut [u8; 16]);
+}
+
+/// NIST SP 800-38G FF1 format-preserving encryption over any [`Base`]: the ciphertext is a string
+/// of the same alphabet and length as the plaintext.
+///
+/// The tweak is public per-use data (like a table or column name) that changes the permutation.
+/// Strings must be between the spec's minimum (`BASE^len >= 1_000_000`) and [`MAX_LEN`] symbols.
+///
+/// ```rust
+/// # #[cfg(feature = "aes")] {
+/// use smol_base_x::{ff1::{Aes128, Ff1}, Base10};
+///
+/// let key = [
+///     0x2b, 0x7e, 0x15, 0x16, 0x28, 0xae, 0xd2, 0xa6, 0xab, 0xf7, 0x15, 0x88, 0x09, 0xcf, 0x4f, 0x3c,
+/// ];
+/// let ff1 = Ff1::new(Aes128::new(key));
+///
+/// let mut buf = [0u8; 16];
+/// let written = ff1.encrypt_mut::<10, Base10, _>(b"", "0123456789", &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"2433477484");
+///
+/// let mut plain = [0u8; 16];
+/// let written = ff1.decrypt_mut::<10, Base10, _>(b"", &buf[..written], &mut plain).unwrap();
+/// assert_eq!(&plain[..written], b"0123456789");
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct Ff1<C> {
+    cipher: C,
+}
+
+/// everything that stays the same between rounds
+struct Setup<'t> {
+    tweak: &'t [u8],
+    /// the fixed first block `P`
+    p: [u8; 16],
+    u: usize,
+    v: usize,
+    /// bytes of `NUM(B)` fed to the PRF
+    b: usize,
+    /// bytes of PRF output turned into `y`
+    d: usize,
+}
+
+impl<C: BlockCipher> Ff1<C> {
+    pub fn new(cipher: C) -> Self {
+        Self { cipher }
+    }
+
+    /// encrypts `input` into `buf`, output is symbols written (always the input length)
+    pub fn encrypt_mut<const BASE: usize, B: Base<BASE>, I: AsRef<[u8]>>(
+        &self,
+        tweak: &[u8],
+        input: I,
+        buf: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        let setup = setup::<BASE, B>(tweak, input.as_ref(), buf)?;
+        let x = &mut buf[..setup.u + setup.v];
+
+        // A is x[..m] and B is x[m..], afterwards B becomes A and the sum becomes B
+        for i in 0..ROUNDS {
+            let m = if i % 2 == 0 { setup.u } else { setup.v };
+            let y = self.round_digits::<BASE, B>(&setup, i, &x[m..], m)?;
+
+            let mut carry = 0;
+            for (ch, digit) in x[..m].iter_mut().zip(&y[..m]).rev() {
+                let sum = digit_of::<BASE, B>(*ch) + *digit as usize + carry;
+                *ch = B::ALPHABET[sum % BASE];
+                carry = sum / BASE;
+            }
+            x.rotate_left(m);
+        }
+
+        Ok(x.len())
+    }
+
+    /// inverse of [`Ff1::encrypt_mut`] with the same tweak, output is symbols written
+    pub fn decrypt_mut<const BASE: usize, B: Base<BASE>, I: AsRef<[u8]>>(
+        &self,
+        tweak: &[u8],
+        input: I,
+        buf: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        let setup = setup::<BASE, B>(tweak, input.as_ref(), buf)?;
+        let n = setup.u + setup.v;
+        let x = &mut buf[..n];
+
+        // B is x[n - m..] and A is x[..n - m], afterwards the difference becomes A and A becomes B
+        for i in (0..ROUNDS).rev() {
+            let m = if i % 2 == 0 { setup.u } else { setup.v };
+            let y = self.round_digits::<BASE, B>(&setup, i, &x[..n - m], m)?;
+
+            let mut borrow = 0;
+            for (ch, digit) in x[n - m..].iter_mut().zip(&y[..m]).rev() {
+                let sub = *digit as usize + borrow;
+                let value = digit_of::<BASE, B>(*ch);
+                borrow = (value < sub) as usize;
+                *ch = B::ALPHABET[value + borrow * BASE - sub];
+            }
+            x.rotate_right(m);
+        }
+
+        Ok(n)
+    }
+
+    /// `y mod BASE^m` as digits, where `y` comes from the PRF over the tweak, round and `half`
+    fn round_digits<const BASE: usize, B: Base<BASE>>(
+        &self,
+        setup: &Setup,
+        round: u8,
+        half: &[u8],
+        m: usize,
+    ) -> Result<[u8; MAX_LEN / 2], DecodeError> {
+        let mut num = [0u8; MAX_LEN / 2];
+        decode_fixed::<BASE, B>(half, &mut num[..setup.b])?;
+
+        let padding = (16 - (setup.tweak.len() + setup.b + 1) % 16) % 16;
+
+        let mut mac = CbcMac::new(&self.cipher);
+        mac.update(&setup.p);
+        mac.update(setup.tweak);
+        mac.update(&[0u8; 16][..padding]);
+        mac.update(&[round]);
+        mac.update(&num[..setup.b]);
+        let r = mac.finalize();
+
+        // S = R || CIPH(R ^ 1) || CIPH(R ^ 2) || ..., cut to d bytes
+        let mut s = [0u8; 80];
+        for (j, block) in s.chunks_exact_mut(16).enumerate() {
+            let mut next = r;
+            if j > 0 {
+                next[15] ^= j as u8;
+                self.cipher.encrypt_block(&mut next);
+            }
+            block.copy_from_slice(&next);
+        }
+
+        let mut y = [0u8; MAX_LEN / 2];
+        // only the low m digits matter, whatever is above them is reduced away
+        fixed_digits::<BASE>(&s[..setup.d], &mut y[..m]);
+        Ok(y)
+    }
+}
+
+/// checks the input and copies it into `buf`, where the rounds happen in place
+fn setup<'t, const BASE: usize, B: Base<BASE>>(
+    tweak: &'t [u8],
+    input: &[u8],
+    buf: &mut [u8],
+) -> Result<Setup<'t>, DecodeError> {
+    let n = input.len();
+
+    let mut min_len = 0;
+    let mut domain = 1u64;
+    while min_len < 2 || domain < MIN_DOMAIN {
+        domain = domain.saturating_mul(BASE as u64);
+        min_len += 1;
+    }
+    if n < min_len {
+        return Err(DecodeError::InvalidLength(min_len));
+    }
+    if n > MAX_LEN {
+        return Err(DecodeError::InvalidLength(MAX_LEN));
+    }
+    if n > buf.len() {
+        return Err(DecodeError::InvalidLength(n));
+    }
+    if input.iter().any(|&ch| B::lookup_ascii(ch).is_none()) {
+        return Err(DecodeError::InvalidChar);
+    }
+    let t = u32::try_from(tweak.len()).map_err(|_| DecodeError::Overflow)?;
+
+    buf[..n].copy_from_slice(input);
+
+    let u = n / 2;
+    let v = n - u;
+
+    // the largest v digit number, its size in bytes is `b`
+    let mut max = [0u8; MAX_LEN / 2];
+    decode_fixed::<BASE, B>(&[B::ALPHABET[BASE - 1]; MAX_LEN / 2][..v], &mut max)?;
+    let b = max.iter().skip_while(|&&byte| byte == 0).count();
+    let d = 4 * b.div_ceil(4) + 4;
+
+    let radix = (BASE as u32).to_be_bytes();
+    let n_bytes = (n as u32).to_be_bytes();
+    let t_bytes = t.to_be_bytes();
+    let p = [
+        1, 2, 1, radix[1], radix[2], radix[3], ROUNDS, u as u8, n_bytes[0], n_bytes[1], n_bytes[2],
+        n_bytes[3], t_bytes[0], t_bytes[1], t_bytes[2], t_bytes[3],
+    ];
+
+    Ok(Setup {
+        tweak,
+        p,
+        u,
+        v,
+        b,
+        d,
+    })
+}
+
+/// only called on chars [`setup`] already checked
+fn digit_of<const BASE: usize, B: Base<BASE>>(ch: u8) -> usize {
+    B::lookup_ascii(ch).unwrap_or(0)
+}
+
+/// CBC-MAC with a zero IV, the spec's PRF. Input is always a whole number of blocks
+struct CbcMac<'c, C> {
+    cipher: &'c C,
+    state: [u8; 16],
+    /// bytes xored into `state` since it was last encrypted
+    filled: usize,
+}
+
+impl<'c, C: BlockCipher> CbcMac<'c, C> {
+    fn new(cipher: &'c C) -> Self {
+        Self {
+            cipher,
+            state: [0; 16],
+            filled: 0,
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            // a full block waits for more data, so the last one is encrypted in finalize
+            if self.filled == 16 {
+                self.cipher.encrypt_block(&mut self.state);
+                self.filled = 0;
+            }
+            self.state[self.filled] ^= byte;
+            self.filled += 1;
+        }
+    }
+
+    fn finalize(mut self) -> [u8; 16] {
+        debug_assert_eq!(self.filled, 16);
+        self.cipher.encrypt_block(&mut self.state);
+        self.state
+    }
+}
@@ -0,0 +1,103 @@
+use core::fmt;
+
+use crate::{Base, Base62Sorted, DecodeError, SortedBase, StackStr};
+
+/// KSUID timestamps count seconds from this unix time (2014-05-13)
+pub const EPOCH: u64 = 1_400_000_000;
+
+/// bytes in a KSUID, a 4 byte timestamp and 16 bytes of payload
+pub const BYTES: usize = 20;
+
+/// chars in an encoded KSUID
+pub const ENCODED_LEN: usize = 27;
+
+/// K-Sortable Unique IDentifier: a big-endian seconds timestamp followed by 16 random bytes,
+/// encoded as 27 chars of [`Base62Sorted`]. Sorting the bytes or the strings sorts by time.
+///
+/// ```rust
+/// use smol_base_x::ksuid::Ksuid;
+///
+/// let id: Ksuid = "0ujtsYcgvSTl8PAuAdqWYSMnLOv".parse().unwrap();
+/// assert_eq!(id.timestamp(), 107608047);
+/// assert_eq!(id.unix_seconds(), 1507608047);
+/// assert_eq!(id.encode().as_str(), "0ujtsYcgvSTl8PAuAdqWYSMnLOv");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ksuid([u8; BYTES]);
+
+impl Ksuid {
+    /// errors with [`DecodeError::Overflow`] if `unix_seconds` is before [`EPOCH`] or too far past it for 32 bits
+    pub fn new(unix_seconds: u64, payload: [u8; 16]) -> Result<Self, DecodeError> {
+        let timestamp = unix_seconds
+            .checked_sub(EPOCH)
+            .and_then(|timestamp| u32::try_from(timestamp).ok())
+            .ok_or(DecodeError::Overflow)?;
+
+        let mut bytes = [0u8; BYTES];
+        bytes[..4].copy_from_slice(&timestamp.to_be_bytes());
+        bytes[4..].copy_from_slice(&payload);
+        Ok(Ksuid(bytes))
+    }
+
+    /// [`Ksuid::new`] with the payload filled in by `fill`, e.g. a CSPRNG's `fill_bytes`
+    pub fn generate(unix_seconds: u64, fill: impl FnOnce(&mut [u8])) -> Result<Self, DecodeError> {
+        let mut payload = [0u8; 16];
+        fill(&mut payload);
+        Self::new(unix_seconds, payload)
+    }
+
+    pub fn from_bytes(bytes: [u8; BYTES]) -> Self {
+        Ksuid(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; BYTES] {
+        &self.0
+    }
+
+    /// seconds since [`EPOCH`]
+    pub fn timestamp(&self) -> u32 {
+        u32::from_be_bytes([self.0[0], self.0[1], self.0[2], self.0[3]])
+    }
+
+    pub fn unix_seconds(&self) -> u64 {
+        EPOCH + self.timestamp() as u64
+    }
+
+    pub fn payload(&self) -> &[u8] {
+        &self.0[4..]
+    }
+
+    pub fn encode(&self) -> StackStr<ENCODED_LEN> {
+        let mut buf = [0u8; ENCODED_LEN];
+        Base62Sorted::encode_sortable_mut(self.0, &mut buf)
+            .expect("20 bytes always fit in 27 base62 chars");
+        StackStr::from_tail(buf, ENCODED_LEN)
+    }
+
+    /// parses the 27 char form, values above the largest KSUID are rejected with [`DecodeError::Overflow`]
+    pub fn parse<I: AsRef<[u8]>>(input: I) -> Result<Self, DecodeError> {
+        let input = input.as_ref();
+
+        if input.len() != ENCODED_LEN {
+            return Err(DecodeError::InvalidLength(ENCODED_LEN));
+        }
+
+        let mut bytes = [0u8; BYTES];
+        Base62Sorted::decode_fixed_mut(input, &mut bytes)?;
+        Ok(Ksuid(bytes))
+    }
+}
+
+impl core::str::FromStr for Ksuid {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Ksuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
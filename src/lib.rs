@@ -11,6 +11,8 @@ extern crate std;
 
 mod base;
 
+mod stack_str;
+
 mod base_impl;
 #[cfg(feature = "unstable")]
 mod utf_base;
@@ -20,11 +22,73 @@ mod utf_base;
 /// One day (soon™) the log implemenation should be replaced with the proper function from rustlang
 pub mod util;
 
+mod sha256;
+
+mod blake2b;
+
+#[cfg(feature = "aes")]
+mod aes;
+
+/// checksums that can be appended to any [`Base`] encoding through [`checksum::Checked`]
+pub mod checksum;
+
+/// single check symbols computed over the encoded string (Luhn mod N, Damm, Verhoeff)
+pub mod check_digit;
+
+/// Reed-Solomon error correcting symbols over the alphabet
+pub mod reed_solomon;
+
+/// bit-oriented base16, base32, base64 (and friends) from RFC 4648
+pub mod rfc4648;
+
+/// the base85 family: Adobe Ascii85, ZeroMQ Z85 and the RFC 1924 IPv6 form of [`Base85Rfc1924`]
+pub mod base85;
+
+/// RFC 9285 Base45, as used for QR code alphanumeric payloads
+pub mod base45;
+
+/// BIP-173 bech32 and BIP-350 bech32m, including segwit addresses
+pub mod bech32;
+
+/// Monero's block base58, fixed 11 char groups for every 8 bytes
+pub mod monero;
+
+/// multibase, a one char code in front of the encoded data naming its encoding
+pub mod multibase;
+
+/// IPFS CIDv0 (`Qm...`) strings and the multihash they hold
+pub mod cid;
+
+/// Crockford base32 integers and the mod 37 check symbol, see [`Base32Crockford`]
+pub mod crockford;
+
+/// Substrate SS58 addresses: a network prefix, the payload and a Blake2b checksum
+pub mod ss58;
+
+/// KSUID, time sortable 27 char base62 identifiers
+pub mod ksuid;
+
+/// ULID, time sortable 26 char Crockford base32 identifiers
+pub mod ulid;
+
+/// UUIDs as fixed 22 char strings, compatible with python's shortuuid
+pub mod shortuuid;
+
+/// Sqids, short non-sequential ids from lists of integers
+pub mod sqids;
+
+/// FF1 format-preserving encryption, ciphertexts in the same alphabet and length as the plaintext
+pub mod ff1;
+
+/// Base58Check as used by Bitcoin: a version byte, the payload and a 4 byte double-SHA256 checksum
+pub mod base58check;
+
 #[cfg(feature = "unstable")]
 /// proc macros for generating match statments for alphabets
 pub use match_lookup::{gen_ascii_match, gen_char_match};
 
-pub use base::Base;
+pub use base::{transcode, Base, SortedBase};
+pub use stack_str::StackStr;
 pub use base_impl::*;
 #[cfg(feature = "unstable")]
 /// UTF base shoudn't really be used (especially if you are reaching for this crate) and the implementation is probably broken
@@ -39,6 +103,24 @@ pub enum DecodeError {
     /// char not in alphabet
     InvalidChar,
     CharAfterTrailingSpaces,
+    /// decoded data did not match its checksum
+    ChecksumMismatch,
+    /// more symbols were wrong than the error correction can fix
+    Uncorrectable,
+    /// padding is missing, misplaced or too long
+    InvalidPadding,
+    /// unused bits of the last symbol were not zero, so the input isn't the canonical encoding
+    NonCanonical,
+    /// decoded value does not fit in its fixed size output
+    Overflow,
+    /// input mixes upper and lower case where only one is allowed
+    MixedCase,
+    /// leading code doesn't name a known encoding
+    UnknownPrefix,
+    /// every output that could be generated contains a blocklisted word
+    Blocked,
+    /// the algorithm doesn't work with an alphabet of this size
+    UnsupportedBase,
 }
 
 #[cfg(test)]
@@ -89,6 +171,1922 @@ mod tests {
         }
     }
 
+    mod base58check {
+        use crate::{base58check::*, DecodeError};
+
+        // from bitcoin core's base58_encode_decode.json
+        const ENCODE_DECODE: [(&str, &str); 14] = [
+            ("", ""),
+            ("61", "2g"),
+            ("626262", "a3gV"),
+            ("636363", "aPEr"),
+            ("73696d706c792061206c6f6e6720737472696e67", "2cFupjhnEsSn59qHXstmK2ffpLv2"),
+            (
+                "00eb15231dfceb60925886b67d065299925915aeb172c06647",
+                "1NS17iag9jJgTHD1VXjvLCEnZuQ3rJDE9L",
+            ),
+            ("516b6fcd0f", "ABnLTmg"),
+            ("bf4f89001e670274dd", "3SEo3LWLoPntC"),
+            ("572e4794", "3EFU7m"),
+            ("ecac89cad93923c02321", "EJDM8drfXA6uyA"),
+            ("10c8511e", "Rt5zm"),
+            ("00000000000000000000", "1111111111"),
+            (
+                "000111d38e5fc9071ffcd20b4a763cc9ae4f252bb4e48fd66a835e252ada93ff480d6dd43dc62a641155a5",
+                "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+            ),
+            (
+                "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f202122232425262728292a2b2c2d2e2f303132333435363738393a3b3c3d3e3f404142434445464748494a4b4c4d4e4f505152535455565758595a5b5c5d5e5f606162636465666768696a6b6c6d6e6f707172737475767778797a7b7c7d7e7f808182838485868788898a8b8c8d8e8f909192939495969798999a9b9c9d9e9fa0a1a2a3a4a5a6a7a8a9aaabacadaeafb0b1b2b3b4b5b6b7b8b9babbbcbdbebfc0c1c2c3c4c5c6c7c8c9cacbcccdcecfd0d1d2d3d4d5d6d7d8d9dadbdcdddedfe0e1e2e3e4e5e6e7e8e9eaebecedeeeff0f1f2f3f4f5f6f7f8f9fafbfcfdfeff",
+                "1cWB5HCBdLjAuqGGReWE3R3CguuwSjw6RHn39s2yuDRTS5NsBgNiFpWgAnEx6VQi8csexkgYw3mdYrMHr8x9i7aEwP8kZ7vccXWqKDvGv3u1GxFKPuAkn8JCPPGDMf3vMMnbzm6Nh9zh1gcNsMvH3ZNLmP5fSG6DGbbi2tuwMWPthr4boWwCxf7ewSgNQeacyozhKDDQQ1qL5fQFUW52QKUZDZ5fw3KXNQJMcNTcaB723LchjeKun7MuGW5qyCBZYzA1KjofN1gYBV3NqyhQJ3Ns746GNuf9N2pQPmHz4xpnSrrfCvy6TVVz5d4PdrjeshsWQwpZsZGzvbdAdN8MKV5QsBDY",
+            ),
+        ];
+
+        // from bitcoin core's key_io_valid.json (and base58_keys_valid.json before it):
+        // pubkey and script hash addresses, then private keys (a trailing 01 marks a compressed one)
+        const VECTORS: [(&str, u8, &str); 13] = [
+            ("1AGNa15ZQXAZUgFiqJ2i7Z2DPU2J6hW62i", 0, "65a16059864a2fdbc7c99a4723a8395bc6f188eb"),
+            ("1Ax4gZtb7gAit2TivwejZHYtNNLT18PUXJ", 0, "6d23156cbbdcc82a5a47eee4c2c7c583c18b6bf4"),
+            ("3CMNFxN1oHBc4R1EpboAL5yzHGgE611Xou", 5, "74f209f6ea907e2ea48f74fae05782ae8a665257"),
+            ("3QjYXhTkvuj8qPaXHTTWb5wjXhdsLAAWVy", 5, "fcc5460dd6e2487c7d75b1963625da0e8f4c5975"),
+            ("mo9ncXisMeAoXwqcV5EWuyncbmCcQN4rVs", 111, "53c0307d6851aa0ce7825ba883c6bd9ad242b486"),
+            ("n3ZddxzLvAY9o7184TB4c6FJasAybsw4HZ", 111, "f1d470f9b02370fdec2e6b708b08ac431bf7a5f7"),
+            (
+                "2N2JD6wb56AfK4tfmM6PwdVmoYk2dCKf4Br",
+                196,
+                "6349a418fc4578d10a372b54b45c280cc8c4382f",
+            ),
+            (
+                "2NBFNJTktNa7GZusGbDbGKRZTxdK9VVez3n",
+                196,
+                "c579342c2c4c9220205e2cdc285617040c924a0a",
+            ),
+            (
+                "5Kd3NBUAdUnhyzenEwVLy9pBKxSwXvE9FMPyR4UKZvpe6E3AgLr",
+                128,
+                "eddbdc1168f1daeadbd3e44c1e3f8f5a284c2029f78ad26af98583a499de5b19",
+            ),
+            (
+                "5K494XZwps2bGyeL71pWid4noiSNA2cfCibrvRWqcHSptoFn7rc",
+                128,
+                "a326b95ebae30164217d7a7f57d72ab2b54e3be64928a19da0210b9568d4015e",
+            ),
+            (
+                "Kz6UJmQACJmLtaQj5A3JAge4kVTNQ8gbvXuwbmCj7bsaabudb3RD",
+                128,
+                "55c9bccb9ed68446d1b75273bbce89d7fe013a8acd1625514420fb2aca1a21c401",
+            ),
+            (
+                "9213qJab2HNEpMpYNBa7wHGFKKbkDn24jpANDs2huN3yi4J11ko",
+                239,
+                "36cb93b9ab1bdabf7fb9f2c04f1b9cc879933530ae7842398eef5a63a56800c2",
+            ),
+            (
+                "cTpB4YiyKiBcPxnefsDpbnDxFDffjqJob8wGCEDXxgQ7zQoMXJdH",
+                239,
+                "b9f4892c9e8282028fea1d2667c4dc5213564d41fc5783896a0d843fc15089f301",
+            ),
+        ];
+
+        #[test]
+        fn bitcoin_core_encode_decode() {
+            use crate::{Base, Base58Btc};
+
+            for (hex, encoded) in ENCODE_DECODE {
+                let bytes = hex::decode(hex).unwrap();
+
+                let mut buf = [0u8; 512];
+                let written = Base58Btc::encode_mut(&bytes, &mut buf).unwrap();
+                assert_eq!(&buf[..written], encoded.as_bytes());
+
+                let mut buf = [0u8; 512];
+                let len = Base58Btc::decode_mut(encoded, &mut buf).unwrap();
+                assert_eq!(&buf[..len], bytes.as_slice());
+            }
+        }
+
+        #[test]
+        fn bitcoin_core_vectors() {
+            for (address, version, payload) in VECTORS {
+                let payload = hex::decode(payload).unwrap();
+
+                let mut buf = [0u8; 64];
+                let written = encode_check(version, &payload, &mut buf).unwrap();
+                assert_eq!(&buf[..written], address.as_bytes());
+
+                let mut buf = [0u8; 64];
+                let (decoded_version, len) = decode_check(address, &mut buf).unwrap();
+                assert_eq!(decoded_version, version);
+                assert_eq!(&buf[..len], payload.as_slice());
+            }
+        }
+
+        #[test]
+        fn checksum_mismatch() {
+            let mut buf = [0u8; 64];
+            // last char of 1AGNa15ZQXAZUgFiqJ2i7Z2DPU2J6hW62i changed
+            let result = decode_check("1AGNa15ZQXAZUgFiqJ2i7Z2DPU2J6hW62j", &mut buf);
+            assert!(matches!(result, Err(DecodeError::ChecksumMismatch)));
+        }
+
+        #[test]
+        fn too_short() {
+            let mut buf = [0u8; 64];
+            let result = decode_check("2g", &mut buf);
+            assert!(matches!(result, Err(DecodeError::InvalidLength(_))));
+        }
+
+        #[test]
+        fn sha256_known_answers() {
+            use crate::sha256::Sha256;
+
+            let mut hasher = Sha256::new();
+            hasher.update(b"abc");
+            assert_eq!(
+                hex::encode(hasher.finalize()),
+                "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+            );
+
+            // two block message, fed in uneven pieces
+            let mut hasher = Sha256::new();
+            hasher.update(b"abcdbcdecdefdefgefghfghighij");
+            hasher.update(b"hijkijkljklmklmnlmnomnopnopq");
+            assert_eq!(
+                hex::encode(hasher.finalize()),
+                "248d6a61d20638b8e5c026930c3e6039a33ce45964ff2167f6ecedd419db06c1"
+            );
+        }
+    }
+
+    mod checksum {
+        use crate::{checksum::*, Base36, Base58Btc, Base62, DecodeError};
+
+        #[test]
+        fn crc_check_values() {
+            // the catalogue "check" value for each CRC is over b"123456789"
+            assert_eq!(Crc16Xmodem::checksum(b"123456789"), 0x31C3u16.to_be_bytes());
+            assert_eq!(Crc32::checksum(b"123456789"), 0xCBF43926u32.to_be_bytes());
+        }
+
+        #[test]
+        fn checked_roundtrip() {
+            let input = b"order-1234";
+
+            let mut buf = [0u8; 64];
+            let written = Checked::<Base36, Crc16Xmodem>::encode_mut(input, &mut buf).unwrap();
+
+            let mut decoded = [0u8; 64];
+            let len = Checked::<Base36, Crc16Xmodem>::decode_mut(&buf[..written], &mut decoded)
+                .unwrap();
+            assert_eq!(&decoded[..len], input);
+        }
+
+        #[test]
+        fn checked_detects_change() {
+            let mut buf = [0u8; 64];
+            let written = Checked::<Base62, Crc32>::encode_mut(b"abc", &mut buf).unwrap();
+
+            buf[0] = if buf[0] == b'1' { b'2' } else { b'1' };
+
+            let mut decoded = [0u8; 64];
+            let result = Checked::<Base62, Crc32>::decode_mut(&buf[..written], &mut decoded);
+            assert!(matches!(result, Err(DecodeError::ChecksumMismatch)));
+        }
+
+        #[test]
+        fn double_sha256_matches_base58check() {
+            // version 0 and 20 zero bytes, the same payload base58check uses in its docs
+            let mut buf = [0u8; 64];
+            let written = Checked::<Base58Btc, DoubleSha256>::encode_mut([0u8; 21], &mut buf)
+                .unwrap();
+            assert_eq!(&buf[..written], b"1111111111111111111114oLvT2");
+        }
+    }
+
+    mod check_digit {
+        use crate::{check_digit::*, Base, Base10, Base11, Base2, Base36, Base58Btc, DecodeError};
+
+        /// every single symbol substitution and adjacent transposition of `code` must fail validation
+        fn assert_detects<C: CheckDigit<10>>(code: &[u8], transpositions: bool) {
+            let mut altered = [0u8; 32];
+            let altered = &mut altered[..code.len()];
+
+            for i in 0..code.len() {
+                for digit in b'0'..=b'9' {
+                    if digit == code[i] {
+                        continue;
+                    }
+                    altered.copy_from_slice(code);
+                    altered[i] = digit;
+                    assert!(C::validate::<Base10>(altered).is_err());
+                }
+
+                if transpositions && i + 1 < code.len() && code[i] != code[i + 1] {
+                    altered.copy_from_slice(code);
+                    altered.swap(i, i + 1);
+                    assert!(C::validate::<Base10>(altered).is_err());
+                }
+            }
+        }
+
+        #[test]
+        fn known_check_digits() {
+            assert_eq!(Damm::compute::<Base10>(b"572").unwrap(), 4);
+            assert_eq!(Verhoeff::compute::<Base10>(b"236").unwrap(), 3);
+            assert_eq!(Verhoeff::compute::<Base10>(b"12345").unwrap(), 1);
+            // plain Luhn
+            assert_eq!(LuhnModN::compute::<Base10>(b"7992739871").unwrap(), 3);
+        }
+
+        #[test]
+        fn detects_errors() {
+            for code in [&b"0"[..], b"572", b"987654321", b"1029384756"] {
+                let mut buf = [0u8; 32];
+                buf[..code.len()].copy_from_slice(code);
+                buf[code.len()] = b'0' + Damm::compute::<Base10>(code).unwrap() as u8;
+                assert_detects::<Damm>(&buf[..code.len() + 1], true);
+
+                buf[code.len()] = b'0' + Verhoeff::compute::<Base10>(code).unwrap() as u8;
+                assert_detects::<Verhoeff>(&buf[..code.len() + 1], true);
+
+                buf[code.len()] = b'0' + LuhnModN::compute::<Base10>(code).unwrap() as u8;
+                assert_detects::<LuhnModN>(&buf[..code.len() + 1], false);
+            }
+        }
+
+        /// every single symbol substitution of `code` must fail validation
+        fn assert_luhn_detects<const BASE: usize, B: Base<BASE>>(code: &[u8]) {
+            let mut buf = [0u8; 32];
+            buf[..code.len()].copy_from_slice(code);
+            buf[code.len()] = B::ALPHABET[LuhnModN::compute::<B>(code).unwrap()];
+            let code = &buf[..code.len() + 1];
+            assert!(LuhnModN::validate::<B>(code).is_ok());
+
+            let mut altered = [0u8; 32];
+            let altered = &mut altered[..code.len()];
+            for i in 0..code.len() {
+                for &symbol in B::ALPHABET.iter().filter(|&&symbol| symbol != code[i]) {
+                    altered.copy_from_slice(code);
+                    altered[i] = symbol;
+                    assert!(LuhnModN::validate::<B>(altered).is_err());
+                }
+            }
+        }
+
+        #[test]
+        fn luhn_mod_n_other_alphabets() {
+            assert_luhn_detects::<36, Base36>(b"zx0c9");
+            assert_luhn_detects::<58, Base58Btc>(b"3yQzZ1");
+            assert_luhn_detects::<2, Base2>(b"1011");
+
+            // odd bases are an error rather than a check symbol that misses substitutions
+            let mut buf = [0u8; 32];
+            assert!(matches!(
+                LuhnModN::encode_mut::<Base11, _>(b"hi", &mut buf),
+                Err(DecodeError::UnsupportedBase)
+            ));
+            assert!(matches!(
+                LuhnModN::validate::<Base11>(b"51a"),
+                Err(DecodeError::UnsupportedBase)
+            ));
+        }
+
+        #[test]
+        fn luhn_mod_n_roundtrip() {
+            let mut buf = [0u8; 32];
+            let written = LuhnModN::encode_mut::<Base36, _>(b"hi there", &mut buf).unwrap();
+            assert!(LuhnModN::validate::<Base36>(&buf[..written]).is_ok());
+
+            let mut decoded = [0u8; 32];
+            let len = LuhnModN::decode_mut::<Base36, _>(&buf[..written], &mut decoded).unwrap();
+            assert_eq!(&decoded[..len], b"hi there");
+
+            buf[0] = if buf[0] == b'z' { b'y' } else { b'z' };
+            let result = LuhnModN::decode_mut::<Base36, _>(&buf[..written], &mut decoded);
+            assert!(matches!(result, Err(DecodeError::ChecksumMismatch)));
+        }
+
+        #[test]
+        fn invalid_symbol() {
+            let result = Damm::validate::<Base10>(b"57a4");
+            assert!(matches!(result, Err(DecodeError::InvalidChar)));
+        }
+    }
+
+    mod reed_solomon {
+        use rand::{Rng, SeedableRng};
+
+        use crate::{reed_solomon::*, Base, Base11, Base58Btc, Base67, DecodeError};
+
+        /// flips `count` distinct symbols to some other symbol of the alphabet
+        fn corrupt<const BASE: usize, B: Base<BASE>>(
+            encoded: &mut [u8],
+            count: usize,
+            rng: &mut impl Rng,
+        ) -> [usize; 16] {
+            let mut positions = [usize::MAX; 16];
+            let mut picked = 0;
+            while picked < count {
+                let at = rng.gen_range(0..encoded.len());
+                if positions.contains(&at) {
+                    continue;
+                }
+                let current = B::lookup_ascii(encoded[at]).unwrap();
+                let offset = rng.gen_range(1..BASE);
+                encoded[at] = B::ALPHABET[(current + offset) % BASE];
+                positions[picked] = at;
+                picked += 1;
+            }
+            positions
+        }
+
+        #[test]
+        fn clean_roundtrip() {
+            let mut encoded = [0u8; 64];
+            let written = ReedSolomon::<Base58Btc>::encode_mut(b"abc", 6, &mut encoded).unwrap();
+            // 58 isn't prime, so each of the 6 parity values takes two symbols
+            assert_eq!(written, 4 + 12);
+            assert_eq!(&encoded[..4], b"ZiCa");
+
+            let mut decoded = [0u8; 16];
+            let mut fixed = [0usize; 3];
+            let (len, fixed_count) =
+                ReedSolomon::<Base58Btc>::decode_mut(&mut encoded[..written], 6, &mut decoded, &mut fixed)
+                    .unwrap();
+            assert_eq!(&decoded[..len], b"abc");
+            assert_eq!(fixed_count, 0);
+        }
+
+        #[test]
+        fn corrects_prime_base() {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(67);
+
+            for errors in 0..=4 {
+                let mut encoded = [0u8; 64];
+                let written =
+                    ReedSolomon::<Base67>::encode_mut(b"label #1234", 8, &mut encoded).unwrap();
+                let original = encoded;
+
+                let positions = corrupt::<67, Base67>(&mut encoded[..written], errors, &mut rng);
+
+                let mut fixed = [0usize; 4];
+                let fixed_count =
+                    ReedSolomon::<Base67>::correct_mut(&mut encoded[..written], 8, &mut fixed).unwrap();
+
+                assert_eq!(encoded, original);
+                assert_eq!(fixed_count, errors);
+                for at in &fixed[..fixed_count] {
+                    assert!(positions.contains(at));
+                }
+            }
+        }
+
+        #[test]
+        fn corrects_embedded_base() {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(58);
+
+            for _ in 0..32 {
+                let mut encoded = [0u8; 64];
+                let written = ReedSolomon::<Base58Btc>::encode_mut(b"hello", 6, &mut encoded).unwrap();
+                let original = encoded;
+
+                corrupt::<58, Base58Btc>(&mut encoded[..written], 3, &mut rng);
+
+                let mut decoded = [0u8; 16];
+                let mut fixed = [0usize; 3];
+                let (len, _) =
+                    ReedSolomon::<Base58Btc>::decode_mut(&mut encoded[..written], 6, &mut decoded, &mut fixed)
+                        .unwrap();
+                assert_eq!(&decoded[..len], b"hello");
+                assert_eq!(encoded, original);
+            }
+        }
+
+        #[test]
+        fn symbols_outside_alphabet_are_errors() {
+            let mut encoded = [0u8; 32];
+            let written = ReedSolomon::<Base11>::encode_mut([0x42], 2, &mut encoded).unwrap();
+            let original = encoded;
+            encoded[0] = b'?';
+
+            let mut fixed = [0usize; 1];
+            let fixed_count =
+                ReedSolomon::<Base11>::correct_mut(&mut encoded[..written], 2, &mut fixed).unwrap();
+            assert_eq!(fixed_count, 1);
+            assert_eq!(fixed[0], 0);
+            assert_eq!(encoded, original);
+        }
+
+        #[test]
+        fn too_many_errors() {
+            let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+
+            for _ in 0..32 {
+                let mut encoded = [0u8; 64];
+                let written =
+                    ReedSolomon::<Base67>::encode_mut(b"label #1234", 4, &mut encoded).unwrap();
+                let original = encoded;
+
+                corrupt::<67, Base67>(&mut encoded[..written], 3, &mut rng);
+
+                // either caught, or "corrected" into some other codeword, never back to the original
+                let mut fixed = [0usize; 2];
+                match ReedSolomon::<Base67>::correct_mut(&mut encoded[..written], 4, &mut fixed) {
+                    Err(DecodeError::Uncorrectable) => {}
+                    Err(e) => panic!("unexpected error {:?}", e),
+                    Ok(_) => assert_ne!(encoded, original),
+                }
+            }
+        }
+
+        #[test]
+        fn codeword_too_long() {
+            // GF(11) only allows 10 symbols of data and parity
+            let mut encoded = [0u8; 64];
+            let result = ReedSolomon::<Base11>::encode_mut([0xff; 4], 4, &mut encoded);
+            assert!(matches!(result, Err(DecodeError::InvalidLength(10))));
+        }
+    }
+
+    mod rfc4648 {
+        use crate::{rfc4648::*, DecodeError};
+
+        const INPUTS: [&str; 7] = ["", "f", "fo", "foo", "foob", "fooba", "foobar"];
+
+        fn assert_vectors<const BASE: usize, R: Rfc4648<BASE>>(expected: [&str; 7]) {
+            for (input, expected) in INPUTS.iter().zip(expected) {
+                let mut buf = [0u8; 32];
+                let written = R::encode_mut(input, &mut buf).unwrap();
+                assert_eq!(core::str::from_utf8(&buf[..written]).unwrap(), expected);
+                assert_eq!(written, R::encoded_len(input.len()));
+
+                let mut decoded = [0u8; 32];
+                let len = R::decode_mut(expected, &mut decoded).unwrap();
+                assert_eq!(&decoded[..len], input.as_bytes());
+            }
+        }
+
+        // RFC 4648 §10
+        #[test]
+        fn test_vectors() {
+            assert_vectors::<16, Base16>([
+                "", "66", "666F", "666F6F", "666F6F62", "666F6F6261", "666F6F626172",
+            ]);
+            assert_vectors::<32, Base32>([
+                "", "MY======", "MZXQ====", "MZXW6===", "MZXW6YQ=", "MZXW6YTB", "MZXW6YTBOI======",
+            ]);
+            assert_vectors::<32, Base32NoPad>([
+                "", "MY", "MZXQ", "MZXW6", "MZXW6YQ", "MZXW6YTB", "MZXW6YTBOI",
+            ]);
+            assert_vectors::<32, Base32Hex>([
+                "", "CO======", "CPNG====", "CPNMU===", "CPNMUOG=", "CPNMUOJ1", "CPNMUOJ1E8======",
+            ]);
+            assert_vectors::<64, Base64>([
+                "", "Zg==", "Zm8=", "Zm9v", "Zm9vYg==", "Zm9vYmE=", "Zm9vYmFy",
+            ]);
+            assert_vectors::<64, Base64UrlNoPad>([
+                "", "Zg", "Zm8", "Zm9v", "Zm9vYg", "Zm9vYmE", "Zm9vYmFy",
+            ]);
+        }
+
+        #[test]
+        fn url_alphabet() {
+            let mut buf = [0u8; 8];
+            let written = Base64Url::encode_mut([0xfb, 0xff], &mut buf).unwrap();
+            assert_eq!(&buf[..written], b"-_8=");
+
+            let written = Base64::encode_mut([0xfb, 0xff], &mut buf).unwrap();
+            assert_eq!(&buf[..written], b"+/8=");
+        }
+
+        #[test]
+        fn z_base_32() {
+            let mut buf = [0u8; 8];
+            let written = ZBase32::encode_mut([0xf0, 0xbf, 0xc7], &mut buf).unwrap();
+            assert_eq!(&buf[..written], b"6n9hq");
+
+            let mut decoded = [0u8; 8];
+            let len = ZBase32::decode_mut("6n9hq", &mut decoded).unwrap();
+            assert_eq!(&decoded[..len], &[0xf0, 0xbf, 0xc7]);
+        }
+
+        #[test]
+        fn strict_padding() {
+            let mut buf = [0u8; 16];
+            assert!(matches!(
+                Base64::decode_mut("Zg", &mut buf),
+                Err(DecodeError::InvalidLength(4))
+            ));
+            assert!(matches!(
+                Base64::decode_mut("Z===", &mut buf),
+                Err(DecodeError::InvalidPadding)
+            ));
+            assert!(matches!(
+                Base64::decode_mut("Zm9v====", &mut buf),
+                Err(DecodeError::InvalidPadding)
+            ));
+            assert!(matches!(
+                Base64::decode_mut("Zg==Zg==", &mut buf),
+                Err(DecodeError::InvalidChar)
+            ));
+            assert!(matches!(
+                Base64NoPad::decode_mut("Zg==", &mut buf),
+                Err(DecodeError::InvalidChar)
+            ));
+            assert!(matches!(
+                Base32NoPad::decode_mut("MZX", &mut buf),
+                Err(DecodeError::InvalidLength(_))
+            ));
+        }
+
+        #[test]
+        fn non_canonical_trailing_bits() {
+            let mut buf = [0u8; 16];
+            // "Zh==" carries the same byte as "Zg==" but with a stray low bit set
+            assert!(matches!(
+                Base64::decode_mut("Zh==", &mut buf),
+                Err(DecodeError::NonCanonical)
+            ));
+            assert!(matches!(
+                Base32::decode_mut("MZ======", &mut buf),
+                Err(DecodeError::NonCanonical)
+            ));
+        }
+
+        #[test]
+        fn buffer_too_small() {
+            let mut buf = [0u8; 3];
+            assert!(matches!(
+                Base64::encode_mut("foo", &mut buf),
+                Err(DecodeError::InvalidLength(4))
+            ));
+            assert!(matches!(
+                Base64::decode_mut("Zm9vYmFy", &mut buf),
+                Err(DecodeError::InvalidLength(6))
+            ));
+        }
+    }
+
+    mod base85 {
+        use crate::{base85::*, Base, Base85Rfc1924, DecodeError};
+
+        #[test]
+        fn ascii85_roundtrip() {
+            // checked against python's base64.a85encode
+            let cases: [(&[u8], &str); 4] = [
+                (b"Man is distinguished", "9jqo^BlbD-BleB1DJ+*+F(f,q"),
+                (b"\0\0\0\0abc", "z@:E^"),
+                (&[0xff; 4], "s8W-!"),
+                (b"", ""),
+            ];
+
+            for (input, expected) in cases {
+                let mut buf = [0u8; 64];
+                let written = Ascii85::encode_mut(input, &mut buf).unwrap();
+                assert_eq!(core::str::from_utf8(&buf[..written]).unwrap(), expected);
+
+                let mut decoded = [0u8; 64];
+                let len = Ascii85::decode_mut(expected, &mut decoded).unwrap();
+                assert_eq!(&decoded[..len], input);
+            }
+        }
+
+        #[test]
+        fn ascii85_delimiters_and_whitespace() {
+            let mut decoded = [0u8; 64];
+            let len = Ascii85::decode_mut("<~9jqo^BlbD-Bl\neB1DJ+*+F(f,q~>", &mut decoded).unwrap();
+            assert_eq!(&decoded[..len], b"Man is distinguished");
+
+            let result = Ascii85::decode_mut("<~9jqo^", &mut decoded);
+            assert!(matches!(result, Err(DecodeError::InvalidChar)));
+        }
+
+        #[test]
+        fn ascii85_invalid() {
+            let mut decoded = [0u8; 64];
+            // 'z' can't appear inside a group
+            assert!(matches!(
+                Ascii85::decode_mut("9jzqo", &mut decoded),
+                Err(DecodeError::InvalidChar)
+            ));
+            // "uuuuu" is past u32::MAX
+            assert!(matches!(
+                Ascii85::decode_mut("uuuuu", &mut decoded),
+                Err(DecodeError::Overflow)
+            ));
+            // a single trailing char carries no bytes
+            assert!(matches!(
+                Ascii85::decode_mut("9jqo^B", &mut decoded),
+                Err(DecodeError::InvalidLength(_))
+            ));
+        }
+
+        #[test]
+        fn z85_spec_vector() {
+            let input = [0x86, 0x4F, 0xD2, 0x6F, 0xB5, 0x59, 0xF7, 0x5B];
+
+            let mut buf = [0u8; 16];
+            let written = Z85::encode_mut(input, &mut buf).unwrap();
+            assert_eq!(&buf[..written], b"HelloWorld");
+
+            let mut decoded = [0u8; 16];
+            let len = Z85::decode_mut("HelloWorld", &mut decoded).unwrap();
+            assert_eq!(&decoded[..len], &input);
+        }
+
+        #[test]
+        fn z85_strict_lengths() {
+            let mut buf = [0u8; 16];
+            assert!(matches!(
+                Z85::encode_mut([1, 2, 3], &mut buf),
+                Err(DecodeError::InvalidLength(4))
+            ));
+            assert!(matches!(
+                Z85::decode_mut("Hello", &mut buf[..3]),
+                Err(DecodeError::InvalidLength(4))
+            ));
+            assert!(matches!(
+                Z85::decode_mut("HelloWorl", &mut buf),
+                Err(DecodeError::InvalidLength(10))
+            ));
+            assert!(matches!(
+                Z85::decode_mut("Hello~orld", &mut buf),
+                Err(DecodeError::InvalidChar)
+            ));
+        }
+
+        #[test]
+        fn rfc1924_ipv6() {
+            let addr = 0x1080_0000_0000_0000_0008_0800_200C_417Au128.to_be_bytes();
+            let encoded = Base85Rfc1924::encode_ipv6(addr);
+            assert_eq!(&encoded, b"4)+k&C#VzJ4br>0wv%Yp");
+            assert_eq!(Base85Rfc1924::decode_ipv6(encoded).unwrap(), addr);
+
+            assert_eq!(&Base85Rfc1924::encode_ipv6([0; 16]), b"00000000000000000000");
+            assert!(matches!(
+                Base85Rfc1924::decode_ipv6("~~~~~~~~~~~~~~~~~~~~"),
+                Err(DecodeError::Overflow)
+            ));
+        }
+
+        #[test]
+        fn rfc1924_whole_number() {
+            // the same number through the generic carry loops
+            let addr = 0x1080_0000_0000_0000_0008_0800_200C_417Au128.to_be_bytes();
+            let mut buf = [0u8; 32];
+            let written = Base85Rfc1924::encode_mut(addr, &mut buf).unwrap();
+            assert_eq!(&buf[..written], b"4)+k&C#VzJ4br>0wv%Yp");
+        }
+    }
+
+    mod base91 {
+        use crate::{
+            util::{base91_decoded_size, base91_encoded_size},
+            Base91, Base91Decoder, Base91Encoder, DecodeError,
+        };
+
+        // from the reference C implementation
+        const VECTORS: [(&str, &str); 4] = [
+            ("", ""),
+            ("test", "fPNKd"),
+            ("Hello World!", ">OwJh>Io0Tv!8PE"),
+            ("May a moody baby doom a yam?\n", "8D9Kc)=/2$WzeFui#G9Km+<{VT2u9MZil}[A"),
+        ];
+
+        #[test]
+        fn reference_vectors() {
+            for (input, expected) in VECTORS {
+                let mut buf = [0u8; 64];
+                let written = Base91::encode_mut(input, &mut buf).unwrap();
+                assert_eq!(core::str::from_utf8(&buf[..written]).unwrap(), expected);
+                assert!(written <= base91_encoded_size(input.len()));
+
+                let mut decoded = [0u8; 64];
+                let len = Base91::decode_mut(expected, &mut decoded).unwrap();
+                assert_eq!(&decoded[..len], input.as_bytes());
+                assert!(len <= base91_decoded_size(expected.len()));
+            }
+        }
+
+        #[test]
+        fn streaming_matches_one_shot() {
+            use rand::{Rng, SeedableRng};
+            let mut rng = rand::rngs::StdRng::seed_from_u64(91);
+
+            let mut input = [0u8; 200];
+            rng.fill(&mut input[..]);
+
+            let mut expected = [0u8; 256];
+            let expected_len = Base91::encode_mut(input, &mut expected).unwrap();
+
+            // odd sized pieces so pairs and bytes straddle calls
+            let mut encoder = Base91Encoder::default();
+            let mut encoded = [0u8; 256];
+            let mut written = 0;
+            for chunk in input.chunks(7) {
+                written += encoder.update(chunk, &mut encoded[written..]).unwrap();
+            }
+            written += encoder.finish(&mut encoded[written..]).unwrap();
+            assert_eq!(&encoded[..written], &expected[..expected_len]);
+
+            let mut decoder = Base91Decoder::default();
+            let mut decoded = [0u8; 256];
+            let mut len = 0;
+            for chunk in encoded[..written].chunks(5) {
+                len += decoder.update(chunk, &mut decoded[len..]).unwrap();
+            }
+            len += decoder.finish(&mut decoded[len..]).unwrap();
+            assert_eq!(&decoded[..len], &input);
+        }
+
+        #[test]
+        fn retry_after_short_buffer() {
+            let mut encoder = Base91Encoder::default();
+            let mut encoded = [0u8; 32];
+            assert!(matches!(
+                encoder.update("Hello World!", &mut encoded[..4]),
+                Err(DecodeError::InvalidLength(_))
+            ));
+            let mut written = encoder.update("Hello World!", &mut encoded).unwrap();
+            written += encoder.finish(&mut encoded[written..]).unwrap();
+            assert_eq!(&encoded[..written], b">OwJh>Io0Tv!8PE");
+
+            let mut decoder = Base91Decoder::default();
+            let mut decoded = [0u8; 32];
+            assert!(matches!(
+                decoder.update(&encoded[..written], &mut decoded[..4]),
+                Err(DecodeError::InvalidLength(_))
+            ));
+            // a short buffer part way through a later call doesn't consume anything either
+            let (first, rest) = encoded[..written].split_at(6);
+            let mut len = decoder.update(first, &mut decoded).unwrap();
+            assert!(matches!(
+                decoder.update(rest, &mut decoded[len..len + 1]),
+                Err(DecodeError::InvalidLength(_))
+            ));
+            len += decoder.update(rest, &mut decoded[len..]).unwrap();
+            len += decoder.finish(&mut decoded[len..]).unwrap();
+            assert_eq!(&decoded[..len], b"Hello World!");
+        }
+
+        #[test]
+        fn skips_chars_outside_alphabet() {
+            // the reference decoder drops these, so wrapped output decodes as is
+            let mut decoded = [0u8; 64];
+            let wrapped = "8D9Kc)=/2$WzeFui#G9K\r\nm+<{VT2u9M Zil}[A\n";
+            let len = Base91::decode_mut(wrapped, &mut decoded).unwrap();
+            assert_eq!(&decoded[..len], b"May a moody baby doom a yam?\n");
+
+            let mut decoder = Base91Decoder::default();
+            let mut len = decoder.update("f\tP", &mut decoded).unwrap();
+            len += decoder.update("\n-N'Kd", &mut decoded[len..]).unwrap();
+            len += decoder.finish(&mut decoded[len..]).unwrap();
+            assert_eq!(&decoded[..len], b"test");
+        }
+
+        #[test]
+        fn invalid() {
+            let mut decoded = [0u8; 8];
+            assert!(matches!(
+                Base91::decode_mut("fPNKd", &mut decoded[..2]),
+                Err(DecodeError::InvalidLength(_))
+            ));
+        }
+    }
+
+    mod base45 {
+        use crate::{base45::Base45, DecodeError};
+
+        #[test]
+        fn rfc_9285_examples() {
+            let cases = [
+                ("AB", "BB8"),
+                ("Hello!!", "%69 VD92EX0"),
+                ("base-45", "UJCLQE7W581"),
+                ("ietf!", "QED8WEX0"),
+                ("", ""),
+            ];
+
+            for (input, expected) in cases {
+                let mut buf = [0u8; 32];
+                let written = Base45::encode_mut(input, &mut buf).unwrap();
+                assert_eq!(core::str::from_utf8(&buf[..written]).unwrap(), expected);
+
+                let mut decoded = [0u8; 32];
+                let len = Base45::decode_mut(expected, &mut decoded).unwrap();
+                assert_eq!(&decoded[..len], input.as_bytes());
+            }
+        }
+
+        #[test]
+        fn out_of_range() {
+            let mut decoded = [0u8; 8];
+            // 65536+ can't come from two bytes, RFC 9285 §6
+            assert!(matches!(
+                Base45::decode_mut("GGW", &mut decoded),
+                Err(DecodeError::Overflow)
+            ));
+            assert!(matches!(
+                Base45::decode_mut(":::", &mut decoded),
+                Err(DecodeError::Overflow)
+            ));
+            // trailing pair above 255
+            assert!(matches!(
+                Base45::decode_mut("BB8::", &mut decoded),
+                Err(DecodeError::Overflow)
+            ));
+        }
+
+        #[test]
+        fn invalid_input() {
+            let mut decoded = [0u8; 8];
+            assert!(matches!(
+                Base45::decode_mut("BB8B", &mut decoded),
+                Err(DecodeError::InvalidLength(5))
+            ));
+            assert!(matches!(
+                Base45::decode_mut("bb8", &mut decoded),
+                Err(DecodeError::InvalidChar)
+            ));
+        }
+    }
+
+    mod bech32 {
+        use crate::{bech32::*, DecodeError};
+
+        #[test]
+        fn bip_173_valid() {
+            for input in [
+                "A12UEL5L",
+                "a12uel5l",
+                "an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1tt5tgs",
+                "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw",
+                "11qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqc8247j",
+                "split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w",
+                "?1ezyfcl",
+            ] {
+                let mut data = [0u8; 90];
+                let decoded = decode_mut(input, &mut data).unwrap();
+                assert_eq!(decoded.variant, Variant::Bech32);
+
+                // re-encoding gives back the lower case form
+                let mut buf = [0u8; 90];
+                let written = encode_mut(decoded.hrp, &data[..decoded.len], Variant::Bech32, &mut buf).unwrap();
+                assert!(input.eq_ignore_ascii_case(core::str::from_utf8(&buf[..written]).unwrap()));
+            }
+        }
+
+        #[test]
+        fn bip_350_valid() {
+            for input in [
+                "A1LQFN3A",
+                "a1lqfn3a",
+                "abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx",
+                "split1checkupstagehandshakeupstreamerranterredcaperredlc445v",
+                "?1v759aa",
+            ] {
+                let mut data = [0u8; 90];
+                let decoded = decode_mut(input, &mut data).unwrap();
+                assert_eq!(decoded.variant, Variant::Bech32m);
+            }
+        }
+
+        #[test]
+        fn invalid() {
+            let mut data = [0u8; 90];
+            let mut check = |input: &str, expected: DecodeError| {
+                let result = decode_mut(input, &mut data);
+                assert_eq!(
+                    core::mem::discriminant(&result.unwrap_err()),
+                    core::mem::discriminant(&expected),
+                    "{}",
+                    input
+                );
+            };
+
+            // no separator
+            check("pzry9x0s0muk", DecodeError::InvalidChar);
+            // empty hrp
+            check("1pzry9x0s0muk", DecodeError::InvalidLength(0));
+            check("10a06t8", DecodeError::InvalidLength(0));
+            // 'b' isn't a data char
+            check("x1b4n0q5v", DecodeError::InvalidChar);
+            // checksum too short
+            check("li1dgmt3", DecodeError::InvalidLength(0));
+            // checksum computed with an upper case hrp
+            check("A1G7SGD8", DecodeError::ChecksumMismatch);
+            check("a12UEL5L", DecodeError::MixedCase);
+            // too long overall
+            check(
+                "an84characterslonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1569pvx",
+                DecodeError::InvalidLength(0),
+            );
+            // a bech32 string isn't a valid bech32m one and vice versa is covered by the variant
+            check("a12uel5m", DecodeError::ChecksumMismatch);
+        }
+
+        #[test]
+        fn segwit_addresses() {
+            let cases: [(&str, u8, &str); 2] = [
+                ("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t4", 0, "751e76e8199196d454941c45d1b3a323f1433bd6"),
+                (
+                    "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+                    1,
+                    "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+                ),
+            ];
+
+            for (address, version, program) in cases {
+                let program = hex::decode(program).unwrap();
+
+                let mut buf = [0u8; 90];
+                let written = encode_segwit("bc", version, &program, &mut buf).unwrap();
+                assert_eq!(&buf[..written], address.as_bytes());
+
+                let mut decoded_program = [0u8; 40];
+                let (decoded, decoded_version) = decode_segwit(address, &mut decoded_program).unwrap();
+                assert_eq!(decoded.hrp, "bc");
+                assert_eq!(decoded_version, version);
+                assert_eq!(&decoded_program[..decoded.len], program.as_slice());
+            }
+
+            // version 1 with a bech32 (not bech32m) checksum, from BIP-350
+            let mut program = [0u8; 40];
+            assert!(matches!(
+                decode_segwit("bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd", &mut program),
+                Err(DecodeError::ChecksumMismatch)
+            ));
+        }
+
+        #[test]
+        fn bytes_roundtrip() {
+            let mut buf = [0u8; 90];
+            let written = encode_bytes_mut("npub", &[0xab; 32], Variant::Bech32, &mut buf).unwrap();
+            let encoded = core::str::from_utf8(&buf[..written]).unwrap();
+
+            let mut bytes = [0u8; 64];
+            let decoded = decode_bytes_mut(encoded, &mut bytes).unwrap();
+            assert_eq!(decoded.hrp, "npub");
+            assert_eq!(&bytes[..decoded.len], &[0xab; 32]);
+        }
+
+        #[test]
+        fn longer_than_bip_173() {
+            // NIP-19 nprofile: a pubkey and two relays
+            let nprofile = "nprofile1qqsrhuxx8l9ex335q7he0f09aej04zpazpl0ne2cgukyawd24mayt8gpp4mhxue69uhhytnc9e3k7mgpz4mhxue69uhkg6nzv9ejuumpv34kytnrdaksjlyr9p";
+            let tlv = hex::decode(
+                "00203bf0c63fcb93463407af97a5e5ee64fa883d107ef9e558472c4eb9aaaefa459d\
+                 010d7773733a2f2f722e782e636f6d01157773733a2f2f646a6261732e7361646b622e636f6d",
+            )
+            .unwrap();
+
+            let mut bytes = [0u8; 128];
+            assert!(matches!(
+                decode_bytes_mut(nprofile, &mut bytes),
+                Err(DecodeError::InvalidLength(MAX_LEN))
+            ));
+            let decoded = decode_bytes_with_limit_mut(nprofile, 5000, &mut bytes).unwrap();
+            assert_eq!(decoded.hrp, "nprofile");
+            assert_eq!(decoded.variant, Variant::Bech32);
+            assert_eq!(&bytes[..decoded.len], tlv.as_slice());
+
+            let mut buf = [0u8; 256];
+            assert!(matches!(
+                encode_bytes_mut("nprofile", &tlv, Variant::Bech32, &mut buf),
+                Err(DecodeError::InvalidLength(MAX_LEN))
+            ));
+            let written =
+                encode_bytes_with_limit_mut("nprofile", &tlv, Variant::Bech32, 5000, &mut buf)
+                    .unwrap();
+            assert_eq!(&buf[..written], nprofile.as_bytes());
+
+            // the 5 bit value functions take the same limit
+            let mut data = [0u8; 256];
+            let decoded = decode_with_limit_mut(nprofile, 5000, &mut data).unwrap();
+            let written = encode_with_limit_mut(
+                "nprofile",
+                &data[..decoded.len],
+                Variant::Bech32,
+                5000,
+                &mut buf,
+            )
+            .unwrap();
+            assert_eq!(&buf[..written], nprofile.as_bytes());
+            assert!(matches!(
+                decode_with_limit_mut(nprofile, 130, &mut data),
+                Err(DecodeError::InvalidLength(130))
+            ));
+        }
+
+        #[test]
+        fn locates_errors() {
+            let valid = "abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw";
+            assert_eq!(locate_error(valid), None);
+
+            let mut typo = [0u8; 45];
+            typo.copy_from_slice(valid.as_bytes());
+            for at in [7, 20, 44] {
+                let original = typo[at];
+                typo[at] = if original == b'q' { b'p' } else { b'q' };
+                assert_eq!(locate_error(core::str::from_utf8(&typo).unwrap()), Some(at));
+                typo[at] = original;
+            }
+
+            assert_eq!(locate_error("abcdef1qpzrb9x8gf2tvdw0s3jn54khce6mua7lmqqqxw"), Some(11));
+        }
+    }
+
+    mod monero {
+        use crate::{monero, DecodeError};
+
+        #[test]
+        fn blocks() {
+            // from monero's unit_tests/base58.cpp
+            let cases = [
+                ("00", "11"),
+                ("39", "1z"),
+                ("FF", "5Q"),
+                ("0000", "111"),
+                ("0039", "11z"),
+                ("0100", "15R"),
+                ("FFFF", "LUv"),
+                ("000000", "11111"),
+                ("000039", "1111z"),
+                ("010000", "11LUw"),
+                ("FFFFFF", "2UzHL"),
+                ("FFFFFFFF", "7YXq9G"),
+                ("FFFFFFFFFF", "VtB5VXc"),
+                ("FFFFFFFFFFFF", "3CUsUpv9t"),
+                ("FFFFFFFFFFFFFF", "Ahg1opVcGW"),
+                ("FFFFFFFFFFFFFFFF", "jpXCZedGfVQ"),
+                ("06156013762879F7FFFFFFFFFF", "22222222222VtB5VXc"),
+            ];
+
+            for (input, expected) in cases {
+                let input = hex::decode(input).unwrap();
+
+                let mut buf = [0u8; 32];
+                let written = monero::encode_mut(&input, &mut buf).unwrap();
+                assert_eq!(core::str::from_utf8(&buf[..written]).unwrap(), expected);
+                assert_eq!(written, monero::encoded_size(input.len()));
+
+                let mut decoded = [0u8; 32];
+                let len = monero::decode_mut(expected, &mut decoded).unwrap();
+                assert_eq!(&decoded[..len], input.as_slice());
+            }
+        }
+
+        #[test]
+        fn address() {
+            let address = "4AdUndXHHZ6cfufTMvppY6JwXNouMBzSkbLYfpAV5Usx3skxNgYeYTRj5UzqtReoS44qo9mtmXCqY45DJ852K5Jv2684Rge";
+
+            let mut decoded = [0u8; 69];
+            let len = monero::decode_mut(address, &mut decoded).unwrap();
+            assert_eq!(len, 69);
+            // mainnet public address tag
+            assert_eq!(decoded[0], 0x12);
+
+            let mut buf = [0u8; 95];
+            let written = monero::encode_mut(decoded, &mut buf).unwrap();
+            assert_eq!(&buf[..written], address.as_bytes());
+        }
+
+        #[test]
+        fn invalid_input() {
+            let mut decoded = [0u8; 16];
+            // no block encodes to 1, 4 or 8 chars
+            for input in ["1", "1111", "11111111", "111111111111"] {
+                assert!(matches!(
+                    monero::decode_mut(input, &mut decoded),
+                    Err(DecodeError::InvalidLength(_))
+                ));
+            }
+            // above 0xFF and above u64::MAX
+            assert!(matches!(
+                monero::decode_mut("5R", &mut decoded),
+                Err(DecodeError::Overflow)
+            ));
+            assert!(matches!(
+                monero::decode_mut("jpXCZedGfVR", &mut decoded),
+                Err(DecodeError::Overflow)
+            ));
+            assert!(matches!(
+                monero::decode_mut("11O", &mut decoded),
+                Err(DecodeError::InvalidChar)
+            ));
+        }
+    }
+    mod multibase {
+        use crate::{
+            multibase::{self, Multibase},
+            DecodeError,
+        };
+
+        #[test]
+        fn spec_vectors() {
+            // multibase/tests/test1.csv and test2.csv
+            let cases = [
+                ("yes mani !", "001111001011001010111001100100000011011010110000101101110011010010010000000100001"),
+                ("yes mani !", "7362625631006654133464440102"),
+                ("yes mani !", "9573277761329450583662625"),
+                ("yes mani !", "f796573206d616e692021"),
+                ("yes mani !", "F796573206D616E692021"),
+                ("yes mani !", "bpfsxgidnmfxgsibb"),
+                ("yes mani !", "BPFSXGIDNMFXGSIBB"),
+                ("yes mani !", "vf5in683dc5n6i811"),
+                ("yes mani !", "VF5IN683DC5N6I811"),
+                ("yes mani !", "cpfsxgidnmfxgsibb"),
+                ("yes mani !", "CPFSXGIDNMFXGSIBB"),
+                ("yes mani !", "tf5in683dc5n6i811"),
+                ("yes mani !", "TF5IN683DC5N6I811"),
+                ("yes mani !", "hxf1zgedpcfzg1ebb"),
+                ("yes mani !", "k2lcpzo5yikidynfl"),
+                ("yes mani !", "K2LCPZO5YIKIDYNFL"),
+                ("yes mani !", "Z7Pznk19XTTzBtx"),
+                ("yes mani !", "z7paNL19xttacUY"),
+                ("yes mani !", "meWVzIG1hbmkgIQ"),
+                ("yes mani !", "MeWVzIG1hbmkgIQ=="),
+                ("yes mani !", "ueWVzIG1hbmkgIQ"),
+                ("yes mani !", "UeWVzIG1hbmkgIQ=="),
+                ("\0yes mani !", "90573277761329450583662625"),
+                ("\0yes mani !", "k02lcpzo5yikidynfl"),
+                ("\0yes mani !", "7000745453462015530267151100204"),
+            ];
+
+            for (input, expected) in cases {
+                let base = Multibase::from_code(expected.as_bytes()[0]).unwrap();
+
+                let mut buf = [0u8; 128];
+                let written = base.encode_mut(input, &mut buf).unwrap();
+                assert_eq!(core::str::from_utf8(&buf[..written]).unwrap(), expected);
+
+                let mut decoded = [0u8; 32];
+                let (decoded_base, len) = multibase::decode_mut(expected, &mut decoded).unwrap();
+                assert_eq!(decoded_base, base);
+                assert_eq!(&decoded[..len], input.as_bytes());
+            }
+        }
+
+        #[test]
+        fn codes_roundtrip() {
+            for &base in Multibase::ALL {
+                assert_eq!(Multibase::from_code(base.code()), Some(base));
+
+                let mut buf = [0u8; 128];
+                let written = base.encode_mut([0, 1, 2, 3, 255], &mut buf).unwrap();
+
+                let mut decoded = [0u8; 16];
+                let (_, len) = multibase::decode_mut(&buf[..written], &mut decoded).unwrap();
+                assert_eq!(&decoded[..len], &[0, 1, 2, 3, 255]);
+            }
+        }
+
+        #[test]
+        fn invalid_input() {
+            let mut decoded = [0u8; 16];
+            assert!(matches!(
+                multibase::decode_mut("", &mut decoded),
+                Err(DecodeError::InvalidLength(1))
+            ));
+            assert!(matches!(
+                multibase::decode_mut("Qm", &mut decoded),
+                Err(DecodeError::UnknownPrefix)
+            ));
+            // the prefix also picks the case
+            assert!(matches!(
+                multibase::decode_mut("fABCD", &mut decoded),
+                Err(DecodeError::InvalidChar)
+            ));
+
+            let mut buf = [0u8; 4];
+            assert!(matches!(
+                Multibase::Base16.encode_mut("abc", &mut buf),
+                Err(DecodeError::InvalidLength(7))
+            ));
+        }
+    }
+
+    mod cid {
+        use crate::{
+            cid::{self, Multihash},
+            DecodeError,
+        };
+
+        #[test]
+        fn v0_roundtrip() {
+            let cases = [
+                (
+                    "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
+                    "9d6c2be50f706953479ab9df2ce3edca90b68053c00b3004b7f0accbe1e8eedf",
+                ),
+                // sha2-256 of the empty string
+                (
+                    "QmdfTbBqBPQ7VNxZEYEj14VmRuZBkqFbiwReogJgS1zR1n",
+                    "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855",
+                ),
+            ];
+
+            for (input, digest) in cases {
+                let hash = cid::decode_v0(input).unwrap();
+                assert_eq!(hash.code, cid::SHA2_256);
+                assert_eq!(hash.size(), 32);
+                assert_eq!(hash.digest(), hex::decode(digest).unwrap().as_slice());
+
+                let mut buf = [0u8; 64];
+                let written = cid::encode_v0(&hash, &mut buf).unwrap();
+                assert_eq!(&buf[..written], input.as_bytes());
+            }
+        }
+
+        #[test]
+        fn multihash_bytes() {
+            // identity hash (0x00) and a two byte varint code (blake2b-256, 0xb220)
+            let cases: [(&[u8], u64, &[u8]); 2] = [
+                (&[0x00, 0x03, 1, 2, 3], 0, &[1, 2, 3]),
+                (&[0xa0, 0xe4, 0x02, 0x02, 0xAB, 0xCD], 0xb220, &[0xAB, 0xCD]),
+            ];
+
+            for (bytes, code, digest) in cases {
+                let (hash, read) = Multihash::read(bytes).unwrap();
+                assert_eq!(read, bytes.len());
+                assert_eq!(hash.code, code);
+                assert_eq!(hash.digest(), digest);
+
+                let mut buf = [0u8; 16];
+                let written = hash.write(&mut buf).unwrap();
+                assert_eq!(&buf[..written], bytes);
+            }
+        }
+
+        #[test]
+        fn invalid_input() {
+            // truncated digest and truncated varint
+            assert!(matches!(
+                Multihash::read(&[0x12, 0x20, 0, 0]),
+                Err(DecodeError::InvalidLength(34))
+            ));
+            assert!(matches!(
+                Multihash::read(&[0x80]),
+                Err(DecodeError::InvalidLength(_))
+            ));
+            // 0x12 padded out with an empty continuation group
+            assert!(matches!(
+                Multihash::read(&[0x92, 0x00, 0x00]),
+                Err(DecodeError::NonCanonical)
+            ));
+            assert!(matches!(
+                Multihash::read(&[0xFF; 12]),
+                Err(DecodeError::Overflow)
+            ));
+
+            // a sha2-512 multihash is valid but isn't a CIDv0
+            let hash = Multihash::new(0x13, &[0; 64]).unwrap();
+            let mut buf = [0u8; 128];
+            assert!(matches!(
+                cid::encode_v0(&hash, &mut buf),
+                Err(DecodeError::UnknownPrefix)
+            ));
+
+            // one char short
+            assert!(matches!(
+                cid::decode_v0("QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd"),
+                Err(DecodeError::InvalidLength(34))
+            ));
+        }
+    }
+
+    mod crockford {
+        use crate::{crockford, Base, Base32Crockford, DecodeError};
+
+        #[test]
+        fn numbers() {
+            let cases = [
+                (0, "00"),
+                (1, "11"),
+                (31, "ZZ"),
+                (32, "10*"),
+                (1234, "16JD"),
+                (u64::MAX, "FZZZZZZZZZZZZB"),
+            ];
+
+            for (value, expected) in cases {
+                // u64::MAX fills these exactly
+                let mut buf = [0u8; crockford::U64_LEN + 1];
+                let written = crockford::encode_u64_check(value, &mut buf).unwrap();
+                assert_eq!(core::str::from_utf8(&buf[..written]).unwrap(), expected);
+                assert_eq!(crockford::decode_u64_check(expected).unwrap(), value);
+
+                let written = crockford::encode_u64(value, &mut buf[..crockford::U64_LEN]).unwrap();
+                assert_eq!(&buf[..written], &expected.as_bytes()[..expected.len() - 1]);
+            }
+        }
+
+        #[test]
+        fn aliases_and_hyphens() {
+            assert_eq!(crockford::decode_u64("16j").unwrap(), 1234);
+            assert_eq!(crockford::decode_u64("1-6-J").unwrap(), 1234);
+            assert_eq!(crockford::decode_u64("Il1").unwrap(), 1057);
+            assert_eq!(crockford::decode_u64("oO0").unwrap(), 0);
+            assert_eq!(crockford::decode_u64_check("14u").unwrap(), 36);
+
+            let mut decoded = [0u8; 16];
+            let len = Base32Crockford::decode_mut("oo-1", &mut decoded).unwrap();
+            assert_eq!(&decoded[..len], &[0, 0, 1]);
+        }
+
+        #[test]
+        fn bytes_roundtrip() {
+            let input = [0, 0x12, 0x34, 0x56, 0x78, 0x9A, 0xBC];
+
+            let mut buf = [0u8; 32];
+            let written = crockford::encode_check_mut(input, &mut buf).unwrap();
+
+            let mut decoded = [0u8; 16];
+            let len = crockford::decode_check_mut(&buf[..written], &mut decoded).unwrap();
+            assert_eq!(&decoded[..len], &input);
+
+            let lower = core::str::from_utf8(&buf[..written])
+                .unwrap()
+                .to_lowercase();
+            let len = crockford::decode_check_mut(lower, &mut decoded).unwrap();
+            assert_eq!(&decoded[..len], &input);
+        }
+
+        #[test]
+        fn invalid_input() {
+            assert!(matches!(
+                crockford::decode_u64_check("16JE"),
+                Err(DecodeError::ChecksumMismatch)
+            ));
+            // `U` and friends are only check symbols
+            assert!(matches!(
+                crockford::decode_u64("1U"),
+                Err(DecodeError::InvalidChar)
+            ));
+            assert!(matches!(
+                crockford::decode_u64("G000000000000"),
+                Err(DecodeError::Overflow)
+            ));
+            assert!(matches!(
+                crockford::decode_u64("--"),
+                Err(DecodeError::InvalidLength(1))
+            ));
+        }
+    }
+
+    mod ss58 {
+        use crate::{ss58, DecodeError};
+
+        const ALICE: &str = "d43593c715fdd31c61141abd04a99fd6822c8558854ccde39a5684e7a56da27d";
+
+        #[test]
+        fn alice() {
+            let key = hex::decode(ALICE).unwrap();
+            let cases = [
+                (42, "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY"),
+                (0, "15oF4uVJwmo4TdGW7VfQxNLavjCXviqxT9S1MgbjMNHr6Sp5"),
+                (2, "HNZata7iMYWmk5RvZRTiAsSDhV8366zq2YGb3tLH5Upf74F"),
+                // two byte prefixes
+                (64, "cEaNSpz4PxFcZ7nT1VEKrKewH67rfx6MfcM6yKojyyPz7qaqp"),
+                (255, "yGHXkYLYqxijLKKfd9Q2CB9shRVu8rPNBS53wvwGTutYg4zTg"),
+                (1284, "VdvKmYJfD4VXA9fzz1SbmCo2eYHSzUFbaDCZSuaNKJAe8YNg6"),
+                (16383, "yNa8JpqfFB3q8A29rCwSgxvdU94ufJw2yKKxDgznS5m1PoFvn"),
+            ];
+
+            for (prefix, expected) in cases {
+                let mut buf = [0u8; 64];
+                let written = ss58::encode_mut(prefix, &key, &mut buf).unwrap();
+                assert_eq!(core::str::from_utf8(&buf[..written]).unwrap(), expected);
+
+                let mut decoded = [0u8; 36];
+                let (decoded_prefix, len) = ss58::decode_mut(expected, &mut decoded).unwrap();
+                assert_eq!(decoded_prefix, prefix);
+                assert_eq!(&decoded[..len], key.as_slice());
+            }
+        }
+
+        #[test]
+        fn account_index() {
+            let mut buf = [0u8; 16];
+            let written = ss58::encode_mut(7, &[1, 2, 3, 4], &mut buf).unwrap();
+            assert_eq!(&buf[..written], b"4VHp4GDK");
+
+            let mut decoded = [0u8; 16];
+            let (prefix, len) = ss58::decode_mut("4VHp4GDK", &mut decoded).unwrap();
+            assert_eq!((prefix, &decoded[..len]), (7, [1, 2, 3, 4].as_slice()));
+        }
+
+        #[test]
+        fn invalid_input() {
+            let mut decoded = [0u8; 40];
+            assert!(matches!(
+                ss58::decode_mut("5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQZ", &mut decoded),
+                Err(DecodeError::ChecksumMismatch)
+            ));
+
+            let mut buf = [0u8; 64];
+            assert!(matches!(
+                ss58::encode_mut(16384, &[0; 32], &mut buf),
+                Err(DecodeError::Overflow)
+            ));
+            assert!(matches!(
+                ss58::encode_mut(42, &[0; 20], &mut buf),
+                Err(DecodeError::InvalidLength(32))
+            ));
+        }
+
+        #[test]
+        fn blake2b_known_answers() {
+            use crate::blake2b::Blake2b;
+
+            // RFC 7693 appendix A
+            let mut hasher = Blake2b::new();
+            hasher.update(b"abc");
+            assert_eq!(
+                hex::encode(hasher.finalize()),
+                "ba80a53f981c4d0d6a2797b69f12f6e94c212f14685ac4b74b12bb6fdbffa2d1\
+                 7d87c5392aab792dc252d5de4533cc9518d38aa8dbf1925ab92386edd4009923"
+            );
+
+            // exactly one block, then several blocks fed in uneven pieces
+            let mut hasher = Blake2b::new();
+            hasher.update(&[0; 128]);
+            assert_eq!(
+                hex::encode(&hasher.finalize()[..16]),
+                "865939e120e6805438478841afb739ae"
+            );
+
+            let data: std::vec::Vec<u8> = (0..=255).cycle().take(512).collect();
+            let mut hasher = Blake2b::new();
+            for piece in data.chunks(100) {
+                hasher.update(piece);
+            }
+            assert_eq!(
+                hex::encode(&hasher.finalize()[..16]),
+                "c59ab1095ca4579525338b6b74689ff2"
+            );
+        }
+    }
+
+    mod ids {
+        use crate::{ksuid::Ksuid, ulid::Ulid, DecodeError};
+
+        #[test]
+        fn ksuid() {
+            let id = Ksuid::parse("0ujtsYcgvSTl8PAuAdqWYSMnLOv").unwrap();
+            assert_eq!(id.timestamp(), 107608047);
+            assert_eq!(
+                id.payload(),
+                hex::decode("B5A1CD34B5F99D1154FB6853345C9735").unwrap().as_slice()
+            );
+
+            let rebuilt = Ksuid::new(id.unix_seconds(), id.payload().try_into().unwrap()).unwrap();
+            assert_eq!(rebuilt, id);
+            assert_eq!(format!("{}", rebuilt), "0ujtsYcgvSTl8PAuAdqWYSMnLOv");
+
+            // the extremes
+            assert_eq!(Ksuid::from_bytes([0; 20]).encode().as_str(), "000000000000000000000000000");
+            assert_eq!(Ksuid::from_bytes([0xFF; 20]).encode().as_str(), "aWgEPTl1tmebfsQzFP4bxwgy80V");
+
+            assert!(matches!(
+                Ksuid::parse("aWgEPTl1tmebfsQzFP4bxwgy80W"),
+                Err(DecodeError::Overflow)
+            ));
+            assert!(matches!(
+                Ksuid::new(crate::ksuid::EPOCH - 1, [0; 16]),
+                Err(DecodeError::Overflow)
+            ));
+        }
+
+        #[test]
+        fn ulid() {
+            let id: Ulid = "01ARYZ6S41TSV4RRFFQ69G5FAV".parse().unwrap();
+            assert_eq!(id.timestamp_ms(), 1469918176385);
+            assert_eq!(
+                id.as_bytes(),
+                hex::decode("01563df36481d6764c61efb99302bd5b").unwrap().as_slice()
+            );
+
+            // case-insensitive
+            assert_eq!(Ulid::parse("01aryz6s41tsv4rrffq69g5fav").unwrap(), id);
+
+            let rebuilt = Ulid::new(id.timestamp_ms(), id.random().try_into().unwrap()).unwrap();
+            assert_eq!(format!("{}", rebuilt), "01ARYZ6S41TSV4RRFFQ69G5FAV");
+
+            assert_eq!(Ulid::from_bytes([0xFF; 16]).encode().as_str(), "7ZZZZZZZZZZZZZZZZZZZZZZZZZ");
+            assert!(matches!(
+                Ulid::parse("80000000000000000000000000"),
+                Err(DecodeError::Overflow)
+            ));
+            assert!(matches!(
+                Ulid::new(1 << 48, [0; 10]),
+                Err(DecodeError::Overflow)
+            ));
+        }
+
+        #[test]
+        fn sorted_by_time() {
+            use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+            let mut rng = StdRng::seed_from_u64(44);
+            let mut last_ksuid = Ksuid::from_bytes([0; 20]);
+            let mut last_ulid = Ulid::from_bytes([0; 16]);
+
+            for step in 0..50u64 {
+                let ksuid = Ksuid::generate(1_500_000_000 + step, |buf| rng.fill_bytes(buf)).unwrap();
+                let ulid = Ulid::generate(1_500_000_000_000 + step, |buf| rng.fill_bytes(buf)).unwrap();
+
+                assert!(ksuid > last_ksuid && ksuid.encode() > last_ksuid.encode());
+                assert!(ulid > last_ulid && ulid.encode() > last_ulid.encode());
+                assert_eq!(Ksuid::parse(ksuid.encode()).unwrap(), ksuid);
+                assert_eq!(Ulid::parse(ulid.encode()).unwrap(), ulid);
+
+                last_ksuid = ksuid;
+                last_ulid = ulid;
+            }
+        }
+    }
+
+    mod shortuuid {
+        use crate::{shortuuid, DecodeError};
+
+        #[test]
+        fn python_compatible() {
+            let cases = [
+                (
+                    0x6ca4f0f8_2508_4bac_b8f1_5d1e3da2247au128,
+                    "MLpZDiEXM4VsUryR9oE8uc",
+                    "ER7xaqjKDtUjDpzAAsd8Qh",
+                    "3j0y51gcm0K4lGytuSnmwW",
+                ),
+                (
+                    0,
+                    "2222222222222222222222",
+                    "1111111111111111111111",
+                    "0000000000000000000000",
+                ),
+                (
+                    1,
+                    "2222222222222222222223",
+                    "1111111111111111111112",
+                    "0000000000000000000001",
+                ),
+                (
+                    u128::MAX,
+                    "oZEq7ovRbLq6UnGMPwc8B5",
+                    "YcVfxkQb6JRzqk5kF2tNLv",
+                    "7N42dgm5tFLK9N8MT7fHC7",
+                ),
+            ];
+
+            for (value, base57, base58, base62) in cases {
+                let uuid = value.to_be_bytes();
+
+                assert_eq!(shortuuid::encode(uuid).as_str(), base57);
+                assert_eq!(shortuuid::encode_base58(uuid).as_str(), base58);
+                assert_eq!(shortuuid::encode_base62(uuid).as_str(), base62);
+
+                assert_eq!(shortuuid::decode(base57).unwrap(), uuid);
+                assert_eq!(shortuuid::decode_base58(base58).unwrap(), uuid);
+                assert_eq!(shortuuid::decode_base62(base62).unwrap(), uuid);
+            }
+        }
+
+        #[test]
+        fn invalid_input() {
+            assert!(matches!(
+                shortuuid::decode("MLpZDiEXM4VsUryR9oE8u"),
+                Err(DecodeError::InvalidLength(22))
+            ));
+            // `1` isn't in the base57 alphabet
+            assert!(matches!(
+                shortuuid::decode("1LpZDiEXM4VsUryR9oE8uc"),
+                Err(DecodeError::InvalidChar)
+            ));
+            assert!(matches!(
+                shortuuid::decode("zzzzzzzzzzzzzzzzzzzzzz"),
+                Err(DecodeError::Overflow)
+            ));
+        }
+
+        #[cfg(feature = "uuid")]
+        #[test]
+        fn uuid_crate() {
+            let uuid = uuid::Uuid::from_u128(0x6ca4f0f8_2508_4bac_b8f1_5d1e3da2247a);
+            assert_eq!(shortuuid::encode_uuid(&uuid).as_str(), "MLpZDiEXM4VsUryR9oE8uc");
+            assert_eq!(shortuuid::decode_uuid("MLpZDiEXM4VsUryR9oE8uc").unwrap(), uuid);
+        }
+    }
+
+    mod sqids {
+        use crate::{sqids::Sqids, Base10, DecodeError};
+
+        fn roundtrip(sqids: &Sqids, numbers: &[u64], expected: &str) {
+            let mut buf = [0u8; 128];
+            let written = sqids.encode_mut(numbers, &mut buf).unwrap();
+            assert_eq!(core::str::from_utf8(&buf[..written]).unwrap(), expected);
+
+            let mut decoded = [0u64; 8];
+            let len = sqids.decode_mut(expected, &mut decoded).unwrap();
+            assert_eq!(&decoded[..len], numbers);
+        }
+
+        #[test]
+        fn spec_vectors() {
+            let sqids = Sqids::default();
+            roundtrip(&sqids, &[1, 2, 3], "86Rf07");
+            roundtrip(&sqids, &[0], "bM");
+            roundtrip(&sqids, &[0, 0, 0, 0, 0], "4JOuloIsr6");
+            roundtrip(&sqids, &[u64::MAX], "eIkvoXH40Lmd");
+            roundtrip(&sqids, &[], "");
+
+            roundtrip(
+                &Sqids::default().with_min_length(10),
+                &[1, 2, 3],
+                "86Rf07xd4z",
+            );
+            roundtrip(
+                &Sqids::default().with_min_length(64),
+                &[1, 2, 3],
+                "86Rf07xd4zBmiJXQG6otHEbew02c3PWsUOLZxADhCpKj7aVFv9I8RquYrNlSTMyf",
+            );
+
+            roundtrip(
+                &Sqids::new(b"0123456789abcdef").unwrap(),
+                &[1, 2, 3],
+                "489158",
+            );
+            roundtrip(&Sqids::new(b"abc").unwrap(), &[1, 2, 3], "aacacbaa");
+        }
+
+        #[test]
+        fn blocklist() {
+            let sqids = Sqids::default();
+            roundtrip(&sqids, &[4572721], "aho1e");
+            roundtrip(&sqids, &[100000], "ArUO");
+
+            let blocklist = ["ArUO"];
+            let sqids = Sqids::default().with_blocklist(&blocklist);
+            roundtrip(&sqids, &[4572721], "aho1e");
+            roundtrip(&sqids, &[100000], "QyG4");
+
+            // blocked ids still decode
+            let mut decoded = [0u64; 1];
+            assert_eq!(sqids.decode_mut("ArUO", &mut decoded).unwrap(), 1);
+            assert_eq!(decoded[0], 100000);
+        }
+
+        #[test]
+        fn base_alphabet() {
+            let sqids = Sqids::from_base::<10, Base10>().unwrap();
+
+            let mut buf = [0u8; 64];
+            let written = sqids.encode_mut(&[7, 1234567], &mut buf).unwrap();
+            assert!(buf[..written].iter().all(u8::is_ascii_digit));
+
+            let mut decoded = [0u64; 2];
+            let len = sqids.decode_mut(&buf[..written], &mut decoded).unwrap();
+            assert_eq!(&decoded[..len], &[7, 1234567]);
+        }
+
+        #[test]
+        fn invalid_input() {
+            assert!(matches!(
+                Sqids::new(b"ab"),
+                Err(DecodeError::InvalidLength(3))
+            ));
+            assert!(matches!(Sqids::new(b"abca"), Err(DecodeError::InvalidChar)));
+            assert!(matches!(
+                Sqids::new("abcé".as_bytes()),
+                Err(DecodeError::InvalidChar)
+            ));
+
+            let sqids = Sqids::default();
+            let mut decoded = [0u64; 2];
+            assert!(matches!(
+                sqids.decode_mut("86Rf0*", &mut decoded),
+                Err(DecodeError::InvalidChar)
+            ));
+            assert!(matches!(
+                sqids.decode_mut("86Rf07", &mut decoded),
+                Err(DecodeError::InvalidLength(3))
+            ));
+
+            let mut buf = [0u8; 4];
+            assert!(matches!(
+                sqids.encode_mut(&[1, 2, 3], &mut buf),
+                Err(DecodeError::InvalidLength(_))
+            ));
+        }
+    }
+
+    mod ff1 {
+        use crate::{
+            ff1::{BlockCipher, Ff1},
+            Base10, Base36, Base58Btc, DecodeError,
+        };
+
+        /// not a real cipher, just enough to drive the rounds without the `aes` feature
+        struct Chained;
+
+        impl BlockCipher for Chained {
+            fn encrypt_block(&self, block: &mut [u8; 16]) {
+                for _ in 0..4 {
+                    let mut acc = 0x5a_u8;
+                    for byte in block.iter_mut() {
+                        acc = acc.wrapping_mul(31) ^ *byte;
+                        *byte = acc;
+                    }
+                    block.reverse();
+                }
+            }
+        }
+
+        #[test]
+        fn roundtrip() {
+            let ff1 = Ff1::new(Chained);
+
+            for input in ["000001", "zzzzzzzzzzz", "ab0c3k9xq2w"] {
+                let mut cipher = [0u8; 16];
+                let written = ff1
+                    .encrypt_mut::<36, Base36, _>(b"ids", input, &mut cipher)
+                    .unwrap();
+                assert_eq!(written, input.len());
+                assert_ne!(&cipher[..written], input.as_bytes());
+                assert!(cipher[..written]
+                    .iter()
+                    .all(|ch| ch.is_ascii_digit() || ch.is_ascii_lowercase()));
+
+                let mut plain = [0u8; 16];
+                let written = ff1
+                    .decrypt_mut::<36, Base36, _>(b"ids", &cipher[..written], &mut plain)
+                    .unwrap();
+                assert_eq!(&plain[..written], input.as_bytes());
+            }
+
+            // odd lengths split unevenly, the longest input takes the widest halves
+            let input = [b'z'; crate::ff1::MAX_LEN - 1];
+            let mut cipher = [0u8; crate::ff1::MAX_LEN];
+            let written = ff1
+                .encrypt_mut::<58, Base58Btc, _>(b"", input, &mut cipher)
+                .unwrap();
+            let mut plain = [0u8; crate::ff1::MAX_LEN];
+            let written = ff1
+                .decrypt_mut::<58, Base58Btc, _>(b"", &cipher[..written], &mut plain)
+                .unwrap();
+            assert_eq!(&plain[..written], input.as_slice());
+        }
+
+        #[test]
+        fn tweak_changes_output() {
+            let ff1 = Ff1::new(Chained);
+
+            let mut first = [0u8; 10];
+            let mut second = [0u8; 10];
+            ff1.encrypt_mut::<10, Base10, _>(b"a", "0123456789", &mut first)
+                .unwrap();
+            ff1.encrypt_mut::<10, Base10, _>(b"b", "0123456789", &mut second)
+                .unwrap();
+            assert_ne!(first, second);
+        }
+
+        #[test]
+        fn invalid_input() {
+            let ff1 = Ff1::new(Chained);
+            let mut buf = [0u8; 256];
+
+            // 10^6 is the smallest domain allowed
+            assert!(matches!(
+                ff1.encrypt_mut::<10, Base10, _>(b"", "12345", &mut buf),
+                Err(DecodeError::InvalidLength(6))
+            ));
+            assert!(ff1
+                .encrypt_mut::<10, Base10, _>(b"", "123456", &mut buf)
+                .is_ok());
+            assert!(matches!(
+                ff1.encrypt_mut::<10, Base10, _>(b"", [b'1'; 129], &mut buf),
+                Err(DecodeError::InvalidLength(128))
+            ));
+            assert!(matches!(
+                ff1.encrypt_mut::<10, Base10, _>(b"", "12345a", &mut buf),
+                Err(DecodeError::InvalidChar)
+            ));
+            assert!(matches!(
+                ff1.decrypt_mut::<10, Base10, _>(b"", "1234567", &mut buf[..6]),
+                Err(DecodeError::InvalidLength(7))
+            ));
+        }
+
+        #[cfg(feature = "aes")]
+        #[test]
+        fn nist_samples() {
+            use crate::ff1::Aes128;
+
+            let key = hex::decode("2B7E151628AED2A6ABF7158809CF4F3C").unwrap();
+            let aes = Aes128::new(key.try_into().unwrap());
+
+            // FIPS-197 appendix C.1
+            let mut block: [u8; 16] = hex::decode("00112233445566778899aabbccddeeff")
+                .unwrap()
+                .try_into()
+                .unwrap();
+            Aes128::new(core::array::from_fn(|i| i as u8)).encrypt_block(&mut block);
+            assert_eq!(hex::encode(block), "69c4e0d86a7b0430d8cdb78070b4c55a");
+
+            let ff1 = Ff1::new(aes);
+            let samples: [(&str, &str, &str); 2] = [
+                ("", "0123456789", "2433477484"),
+                ("39383736353433323130", "0123456789", "6124200773"),
+            ];
+            for (tweak, plain, cipher) in samples {
+                let tweak = hex::decode(tweak).unwrap();
+                let mut buf = [0u8; 16];
+                let written = ff1
+                    .encrypt_mut::<10, Base10, _>(&tweak, plain, &mut buf)
+                    .unwrap();
+                assert_eq!(&buf[..written], cipher.as_bytes());
+                let written = ff1
+                    .decrypt_mut::<10, Base10, _>(&tweak, cipher, &mut buf)
+                    .unwrap();
+                assert_eq!(&buf[..written], plain.as_bytes());
+            }
+
+            let tweak = hex::decode("3737373770717273373737").unwrap();
+            let mut buf = [0u8; 32];
+            let written = ff1
+                .encrypt_mut::<36, Base36, _>(&tweak, "0123456789abcdefghi", &mut buf)
+                .unwrap();
+            assert_eq!(&buf[..written], b"a9tv40mll9kdu509eum");
+        }
+    }
+
+    #[cfg(feature = "rand_core")]
+    mod random {
+        use crate::{Base, Base58Btc, Base62, DecodeError};
+        use rand::{rngs::StdRng, SeedableRng};
+
+        /// hands out 0, 1, 2, ... so every rejected byte is known up front
+        struct Counter(u8);
+
+        impl rand_core::RngCore for Counter {
+            fn next_u32(&mut self) -> u32 {
+                rand_core::impls::next_u32_via_fill(self)
+            }
+
+            fn next_u64(&mut self) -> u64 {
+                rand_core::impls::next_u64_via_fill(self)
+            }
+
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                for byte in dest {
+                    *byte = self.0;
+                    self.0 = self.0.wrapping_add(1);
+                }
+            }
+
+            fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+                self.fill_bytes(dest);
+                Ok(())
+            }
+        }
+
+        #[test]
+        fn rejects_out_of_range() {
+            // 58 symbols take 6 bits, so 58 through 63 are thrown away and the alphabet repeats
+            let mut buf = [0u8; 64];
+            let written = Base58Btc::random_string(&mut Counter(0), 64, &mut buf).unwrap();
+            assert_eq!(&buf[..58], Base58Btc::ALPHABET.as_slice());
+            assert_eq!(&buf[58..written], &Base58Btc::ALPHABET[..6]);
+        }
+
+        #[test]
+        fn uniform() {
+            let mut rng = StdRng::seed_from_u64(42);
+            let mut counts = [0usize; 62];
+
+            let mut buf = [0u8; 1000];
+            for _ in 0..62 {
+                let written = Base62::random_string(&mut rng, 1000, &mut buf).unwrap();
+                for &ch in &buf[..written] {
+                    counts[Base62::lookup_ascii(ch).unwrap()] += 1;
+                }
+            }
+
+            // 1000 expected per symbol, a biased modulo would push the first 8 well above it
+            assert!(counts.iter().all(|&count| (850..1150).contains(&count)));
+        }
+
+        #[test]
+        fn buffer_too_small() {
+            let mut rng = StdRng::seed_from_u64(1);
+            let mut buf = [0u8; 8];
+            assert!(matches!(
+                Base62::random_string(&mut rng, 9, &mut buf),
+                Err(DecodeError::InvalidLength(9))
+            ));
+            assert_eq!(Base62::random_string(&mut rng, 0, &mut buf).unwrap(), 0);
+        }
+    }
+
     #[test]
     fn full_cycle() {
         let input = "44Y6qTgSvRMkdqpQ5ufkN";
@@ -227,13 +2225,481 @@ mod tests {
         assert!(matches!(result, Err(DecodeError::CharAfterTrailingSpaces)));
     }
 
+    #[test]
+    fn grouped_roundtrip() {
+        let input = hex::decode("6d691bdd736346aa5a0a95b373b2ab").unwrap();
+        let mut buf = [0u8; 64];
+
+        let written = Base58Btc::encode_grouped_mut(&input, &mut buf, 4, b'-').unwrap();
+        assert_eq!(&buf[..written], b"44Y6-qTgS-vRMk-dqpQ-5ufk-N");
+        assert_eq!(written, crate::util::grouped_size(21, 4));
+
+        let mut decoded = [0u8; 32];
+        let len = Base58Btc::decode_grouped_mut(&buf[..written], &mut decoded, b"-").unwrap();
+        assert_eq!(&decoded[..len], input.as_slice());
+    }
+
+    #[test]
+    fn grouped_leading_zeros_span_separators() {
+        use crate::Base10;
+
+        let mut buf = [0u8; 32];
+        let written = Base10::encode_grouped_mut([0, 0, 0, 1], &mut buf, 2, b' ').unwrap();
+        assert_eq!(&buf[..written], b"00 01");
+
+        let mut decoded = [0u8; 32];
+        let len = Base10::decode_grouped_mut(&buf[..written], &mut decoded, b" ").unwrap();
+        assert_eq!(&decoded[..len], &[0, 0, 0, 1]);
+    }
+
+    #[test]
+    fn wrapped_lines() {
+        let mut buf = [0u8; 64];
+        let written = Base58Btc::encode_wrapped_mut("abcdef", &mut buf, 3).unwrap();
+        let encoded = core::str::from_utf8(&buf[..written]).unwrap();
+        assert!(encoded.lines().all(|line| line.len() <= 3));
+
+        let mut decoded = [0u8; 32];
+        let len = Base58Btc::decode_grouped_mut(encoded, &mut decoded, b"\r\n").unwrap();
+        assert_eq!(&decoded[..len], b"abcdef");
+
+        // without the separators, interior whitespace is still an error
+        let result = Base58Btc::decode_mut(encoded, &mut decoded);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn grouped_buffer_too_small() {
+        use crate::DecodeError;
+
+        // fits the plain encoding, but not the separators
+        let mut buf = [0u8; 5];
+        let result = Base58Btc::encode_grouped_mut("abc", &mut buf, 1, b'-');
+        assert!(matches!(result, Err(DecodeError::InvalidLength(7))));
+    }
+
+    #[test]
+    fn block_sizes() {
+        use crate::{monero, util::block_encoded_size};
+
+        assert_eq!(block_encoded_size(58, 0), 0);
+        // exact powers of two line up with whole bytes
+        assert_eq!(block_encoded_size(16, 4), 8);
+        assert_eq!(block_encoded_size(2, 3), 24);
+        // 10^20 is just above 2^64
+        assert_eq!(block_encoded_size(10, 8), 20);
+        assert_eq!(block_encoded_size(85, 4), 5);
+        assert_eq!(block_encoded_size(58, 64), 88);
+
+        for (bytes, &symbols) in monero::ENCODED_BLOCK_SIZES.iter().enumerate() {
+            assert_eq!(block_encoded_size(58, bytes), symbols);
+        }
+    }
+
+    #[test]
+    fn blocks_roundtrip() {
+        use crate::{Base10, Base2, Base36, Base62};
+        use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+        fn roundtrip<const BASE: usize, B: Base<BASE>>(input: &[u8], block: usize) {
+            let mut buf = [0u8; 1024];
+            let written = B::encode_blocks_mut(input, &mut buf, block).unwrap();
+
+            // encoding a piece at a time gives the same output
+            let mut pieces = [0u8; 1024];
+            let mut pieces_written = 0;
+            for piece in input.chunks(block * 3) {
+                pieces_written +=
+                    B::encode_blocks_mut(piece, &mut pieces[pieces_written..], block).unwrap();
+            }
+            assert_eq!(&pieces[..pieces_written], &buf[..written]);
+
+            let mut decoded = [0u8; 128];
+            let len = B::decode_blocks_mut(&buf[..written], &mut decoded, block).unwrap();
+            assert_eq!(&decoded[..len], input);
+        }
+
+        let mut rng = StdRng::seed_from_u64(37);
+        let mut input = [0u8; 100];
+        rng.fill_bytes(&mut input);
+        input[..3].fill(0);
+
+        for len in [0, 1, 7, 8, 9, 33, 100] {
+            for block in [1, 4, 8, 13] {
+                roundtrip::<2, Base2>(&input[..len], block);
+                roundtrip::<10, Base10>(&input[..len], block);
+                roundtrip::<36, Base36>(&input[..len], block);
+                roundtrip::<62, Base62>(&input[..len], block);
+                roundtrip::<58, Base58Btc>(&input[..len], block);
+            }
+        }
+    }
+
+    #[test]
+    fn blocks_invalid_input() {
+        use crate::{Base10, DecodeError};
+
+        let mut decoded = [0u8; 16];
+        // a 4 byte block is 10 digits, no partial block is 4 digits long
+        assert!(matches!(
+            Base10::decode_blocks_mut("00000000000000", &mut decoded, 4),
+            Err(DecodeError::InvalidLength(20))
+        ));
+        assert!(matches!(
+            Base10::decode_blocks_mut("0000", &mut decoded, 1),
+            Err(DecodeError::InvalidLength(6))
+        ));
+        assert!(matches!(
+            Base10::decode_blocks_mut("4294967296", &mut decoded, 4),
+            Err(DecodeError::Overflow)
+        ));
+
+        // block sizes outside 1..=MAX_BLOCK_SIZE are errors, not panics
+        let mut buf = [0u8; 256];
+        assert!(matches!(
+            Base10::encode_blocks_mut("abc", &mut buf, 0),
+            Err(DecodeError::InvalidLength(1))
+        ));
+        assert!(matches!(
+            Base10::encode_blocks_mut("abc", &mut buf, 65),
+            Err(DecodeError::InvalidLength(64))
+        ));
+        assert!(matches!(
+            Base10::decode_blocks_mut("123", &mut decoded, 0),
+            Err(DecodeError::InvalidLength(1))
+        ));
+        assert!(matches!(
+            Base10::decode_blocks_mut("123", &mut decoded, 65),
+            Err(DecodeError::InvalidLength(64))
+        ));
+    }
+
+    #[test]
+    fn integers() {
+        use crate::{Base2, Base36, Base58Flickr, Base62, DecodeError};
+
+        assert_eq!(Base58Flickr::encode_u64(0).as_str(), "1");
+        assert_eq!(Base58Flickr::encode_u64(1234567890).as_str(), "2T6u2h");
+        assert_eq!(Base58Flickr::encode_u64(u64::MAX).as_str(), "JPwcyDCgEup");
+        assert_eq!(Base36::encode_u32(u32::MAX).as_str(), "1z141z3");
+        assert_eq!(Base62::encode_u128(u128::MAX).as_str(), "7N42dgm5tFLK9N8MT7fHC7");
+        assert_eq!(Base2::encode_u128(u128::MAX).len(), 128);
+
+        assert_eq!(Base58Flickr::decode_u64("2T6u2h").unwrap(), 1234567890);
+        assert_eq!(Base58Flickr::decode_u64("112T6u2h").unwrap(), 1234567890);
+        assert_eq!(Base62::decode_u128("7N42dgm5tFLK9N8MT7fHC7").unwrap(), u128::MAX);
+
+        // agrees with the byte-wise encoding once leading zero bytes are stripped
+        let value = 0x0000_1234_5678_9ABCu64;
+        let mut buf = [0u8; 16];
+        let written = Base58Btc::encode_mut(&value.to_be_bytes()[2..], &mut buf).unwrap();
+        assert_eq!(Base58Btc::encode_u64(value).as_bytes(), &buf[..written]);
+
+        assert!(matches!(
+            Base58Flickr::decode_u64("JPwcyDCgEuq"),
+            Err(DecodeError::Overflow)
+        ));
+        assert!(matches!(
+            Base36::decode_u32("1z141z4"),
+            Err(DecodeError::Overflow)
+        ));
+        assert!(matches!(
+            Base58Flickr::decode_u64("2T6u0h"),
+            Err(DecodeError::InvalidChar)
+        ));
+        assert!(matches!(
+            Base58Flickr::decode_u64(""),
+            Err(DecodeError::InvalidLength(1))
+        ));
+    }
+
+    #[test]
+    fn fixed_width_sorts() {
+        use crate::{
+            Base10, Base11, Base2, Base32Crockford, Base36, Base36Upper, Base57, Base58Flickr,
+            Base62, Base62Sorted, Base67, Base8, DecodeError,
+        };
+        use rand::{rngs::StdRng, Rng, SeedableRng};
+
+        // nothing stops a SortedBase impl on an unsorted alphabet, so check every one
+        fn sorted_alphabet<const BASE: usize, B: SortedBase<BASE>>() -> bool {
+            B::SORTED
+        }
+        let sorted = [
+            sorted_alphabet::<58, Base58Btc>(),
+            sorted_alphabet::<2, Base2>(),
+            sorted_alphabet::<8, Base8>(),
+            sorted_alphabet::<11, Base11>(),
+            sorted_alphabet::<36, Base36>(),
+            sorted_alphabet::<10, Base10>(),
+            sorted_alphabet::<57, Base57>(),
+            sorted_alphabet::<62, Base62Sorted>(),
+            sorted_alphabet::<36, Base36Upper>(),
+            sorted_alphabet::<32, Base32Crockford>(),
+        ];
+        assert_eq!(sorted, [true; 10]);
+        let unsorted = [Base58Flickr::SORTED, Base67::SORTED, Base62::SORTED];
+        assert_eq!(unsorted, [false; 3]);
+
+        let mut rng = StdRng::seed_from_u64(43);
+        let mut values: std::vec::Vec<u64> = (0..200)
+            .map(|_| rng.gen::<u64>() >> rng.gen_range(0..64))
+            .collect();
+        values.extend([0, 1, u64::MAX]);
+
+        let mut encoded: std::vec::Vec<[u8; 13]> = values
+            .iter()
+            .map(|value| {
+                let mut buf = [0u8; 13];
+                let written =
+                    Base32Crockford::encode_sortable_mut(value.to_be_bytes(), &mut buf).unwrap();
+                assert_eq!(written, 13);
+                buf
+            })
+            .collect();
+
+        values.sort();
+        encoded.sort();
+        for (value, buf) in values.iter().zip(&encoded) {
+            let mut decoded = [0u8; 8];
+            assert_eq!(Base32Crockford::decode_fixed_mut(buf, &mut decoded).unwrap(), 8);
+            assert_eq!(u64::from_be_bytes(decoded), *value);
+        }
+
+        let mut buf = [0u8; 8];
+        assert_eq!(Base10::encode_fixed_mut([0, 0], &mut buf).unwrap(), 5);
+        assert_eq!(&buf[..5], b"00000");
+
+        let mut decoded = [0u8; 8];
+        // 5 digits is 2 bytes, 4 digits isn't a width any byte length encodes to
+        assert!(matches!(
+            Base10::decode_fixed_mut("65536", &mut decoded),
+            Err(DecodeError::Overflow)
+        ));
+        assert!(matches!(
+            Base10::decode_fixed_mut("0000", &mut decoded),
+            Err(DecodeError::InvalidLength(5))
+        ));
+
+        // one byte past the largest block is an error, not a panic
+        let mut buf = [0u8; 256];
+        assert!(matches!(
+            Base36::encode_fixed_mut([1u8; 65], &mut buf),
+            Err(DecodeError::InvalidLength(64))
+        ));
+        assert!(matches!(
+            Base36::encode_sortable_mut([1u8; 65], &mut buf),
+            Err(DecodeError::InvalidLength(64))
+        ));
+        assert!(Base36::encode_fixed_mut([1u8; 64], &mut buf).is_ok());
+    }
+
+    #[test]
+    fn entropy() {
+        use crate::util::{block_encoded_size, entropy_bits, symbols_for_bits, MAX_ENTROPY_BITS};
+
+        assert_eq!(symbols_for_bits(2, 128), Some(128));
+        assert_eq!(symbols_for_bits(62, 128), Some(22));
+        assert_eq!(symbols_for_bits(36, 64), Some(13));
+        assert_eq!(symbols_for_bits(10, 0), Some(0));
+        // block sizes are the same question in whole bytes
+        assert_eq!(symbols_for_bits(58, 256), Some(block_encoded_size(58, 32)));
+
+        // well past the largest block
+        assert_eq!(symbols_for_bits(62, 1024), Some(172));
+        assert_eq!(
+            symbols_for_bits(2, MAX_ENTROPY_BITS),
+            Some(MAX_ENTROPY_BITS)
+        );
+        assert_eq!(
+            symbols_for_bits(256, MAX_ENTROPY_BITS),
+            Some(MAX_ENTROPY_BITS / 8)
+        );
+        assert_eq!(symbols_for_bits(62, MAX_ENTROPY_BITS + 1), None);
+        assert_eq!(symbols_for_bits(1, 8), None);
+        assert_eq!(symbols_for_bits(257, 8), None);
+
+        assert!((entropy_bits(16, 32) - 128.0).abs() < 0.01);
+        assert!((entropy_bits(62, 22) - 130.99).abs() < 0.01);
+    }
+
+    #[test]
+    fn transcode_matches_roundtrip() {
+        use crate::{transcode, Base10, Base2, Base36, Base58Flickr, Base62};
+        use rand::{rngs::StdRng, RngCore, SeedableRng};
+
+        fn check<const FROM: usize, F: Base<FROM>, const TO: usize, T: Base<TO>>(input: &[u8]) {
+            let mut from = [0u8; 1024];
+            let from_len = F::encode_mut(input, &mut from).unwrap();
+            let mut to = [0u8; 1024];
+            let to_len = T::encode_mut(input, &mut to).unwrap();
+
+            let mut buf = [0u8; 1024];
+            let written = transcode::<FROM, F, TO, T, _>(&from[..from_len], &mut buf).unwrap();
+            assert_eq!(&buf[..written], &to[..to_len]);
+        }
+
+        let mut rng = StdRng::seed_from_u64(49);
+        let mut input = [0u8; 64];
+        rng.fill_bytes(&mut input);
+        input[..2].fill(0);
+
+        for len in [0, 1, 2, 3, 17, 64] {
+            let input = &input[..len];
+            check::<58, Base58Flickr, 58, Base58Btc>(input);
+            check::<58, Base58Btc, 58, Base58Flickr>(input);
+            check::<62, Base62, 36, Base36>(input);
+            check::<36, Base36, 62, Base62>(input);
+            check::<10, Base10, 2, Base2>(input);
+            check::<2, Base2, 58, Base58Btc>(input);
+        }
+    }
+
+    #[test]
+    fn transcode_invalid_input() {
+        use crate::{transcode, Base36, Base58Flickr, Base62, DecodeError};
+
+        let mut buf = [0u8; 16];
+        assert!(matches!(
+            transcode::<58, Base58Flickr, 58, Base58Btc, _>("0abc", &mut buf),
+            Err(DecodeError::InvalidChar)
+        ));
+        assert!(matches!(
+            transcode::<62, Base62, 36, Base36, _>("zz-", &mut buf),
+            Err(DecodeError::InvalidChar)
+        ));
+        assert!(matches!(
+            transcode::<58, Base58Flickr, 58, Base58Btc, _>("abc", &mut buf[..2]),
+            Err(DecodeError::InvalidLength(3))
+        ));
+        assert!(matches!(
+            transcode::<62, Base62, 36, Base36, _>("zzzzzzzzzz", &mut buf[..8]),
+            Err(DecodeError::InvalidLength(_))
+        ));
+    }
+
+    #[test]
+    fn transcode_long_input() {
+        use crate::{transcode, util::transcoded_size, Base10, Base2, Base36};
+
+        /// `symbols` copies of the largest symbol behind a zero one, into a buffer of exactly the
+        /// estimated size
+        fn check<const FROM: usize, F: Base<FROM>, const TO: usize, T: Base<TO>>(symbols: usize) {
+            let mut input = [F::ALPHABET[FROM - 1]; 1024];
+            input[0] = F::ALPHABET[0];
+            let input = &input[..symbols + 1];
+
+            let mut bytes = [0u8; 1024];
+            let len = F::decode_mut(input, &mut bytes).unwrap();
+            let mut expected = [0u8; 4096];
+            let expected_len = T::encode_mut(&bytes[..len], &mut expected).unwrap();
+
+            let mut buf = [0u8; 4096];
+            let size = 1 + transcoded_size(FROM, TO, symbols);
+            let written = transcode::<FROM, F, TO, T, _>(input, &mut buf[..size]).unwrap();
+            assert_eq!(&buf[..written], &expected[..expected_len]);
+        }
+
+        // lengths where an f32 estimate came out one symbol short
+        check::<58, Base58Btc, 2, Base2>(514);
+        check::<10, Base10, 36, Base36>(926);
+        check::<36, Base36, 2, Base2>(665);
+    }
+
+    #[test]
+    fn numeric_compare() {
+        use crate::{Base10, Base36, Base62, DecodeError};
+        use core::cmp::Ordering;
+
+        assert_eq!(Base10::compare("9", "10").unwrap(), Ordering::Less);
+        assert_eq!(Base10::compare("0009", "9").unwrap(), Ordering::Equal);
+        assert_eq!(Base10::compare("", "000").unwrap(), Ordering::Equal);
+        assert_eq!(Base10::compare("120", "119").unwrap(), Ordering::Greater);
+
+        // string order disagrees with numeric order when the alphabet isn't ASCII sorted
+        assert_eq!(Base62::compare("a", "A").unwrap(), Ordering::Less);
+        assert_eq!(Base58Btc::compare("z", "21").unwrap(), Ordering::Less);
+
+        for (a, b) in [
+            (0u64, 1u64),
+            (35, 36),
+            (1295, 1296),
+            (u64::MAX - 1, u64::MAX),
+        ] {
+            let (a, b) = (Base36::encode_u64(a), Base36::encode_u64(b));
+            assert_eq!(Base36::compare(&*a, &*b).unwrap(), Ordering::Less);
+            assert_eq!(Base36::compare(&*b, &*a).unwrap(), Ordering::Greater);
+        }
+
+        assert!(matches!(
+            Base10::compare("12", "1a"),
+            Err(DecodeError::InvalidChar)
+        ));
+    }
+
+    #[test]
+    fn arithmetic() {
+        use crate::{Base10, Base2, Base36, DecodeError};
+
+        let mut buf = *b"0099";
+        Base10::increment_mut(&mut buf).unwrap();
+        assert_eq!(&buf, b"0100");
+        Base10::decrement_mut(&mut buf).unwrap();
+        assert_eq!(&buf, b"0099");
+        Base10::add_mut(&mut buf, 9900).unwrap();
+        assert_eq!(&buf, b"9999");
+
+        // matches adding the integers
+        let mut buf = *b"11111111111111";
+        Base36::add_mut(&mut buf, u64::MAX).unwrap();
+        let expected = Base36::decode_u128("11111111111111").unwrap() + u64::MAX as u128;
+        assert_eq!(Base36::decode_u128(buf).unwrap(), expected);
+
+        let mut buf = *b"0111";
+        Base2::increment_mut(&mut buf).unwrap();
+        assert_eq!(&buf, b"1000");
+        Base2::decrement_mut(&mut buf).unwrap();
+        assert_eq!(&buf, b"0111");
+
+        // nothing is written when the width runs out
+        let mut buf = *b"999";
+        assert!(matches!(
+            Base10::increment_mut(&mut buf),
+            Err(DecodeError::Overflow)
+        ));
+        assert_eq!(&buf, b"999");
+        assert!(matches!(
+            Base10::add_mut(&mut buf[1..], 1),
+            Err(DecodeError::Overflow)
+        ));
+        assert_eq!(&buf, b"999");
+
+        let mut buf = *b"000";
+        assert!(matches!(
+            Base10::decrement_mut(&mut buf),
+            Err(DecodeError::Overflow)
+        ));
+        assert_eq!(&buf, b"000");
+
+        let mut buf = *b"0x1";
+        assert!(matches!(
+            Base10::increment_mut(&mut buf),
+            Err(DecodeError::InvalidChar)
+        ));
+        assert!(matches!(
+            Base10::increment_mut(&mut []),
+            Err(DecodeError::Overflow)
+        ));
+        Base10::add_mut(&mut [], 0).unwrap();
+    }
+
     #[test]
     fn encode_all_zeros() {
         use crate::Base10;
 
         // All zero bytes should produce all first-alphabet chars
         let mut buf = [0u8; 32];
-        let written = Base10::encode_mut(&[0, 0, 0], &mut buf).unwrap();
+        let written = Base10::encode_mut([0, 0, 0], &mut buf).unwrap();
         let encoded = core::str::from_utf8(&buf[..written]).unwrap();
         assert_eq!(encoded, "000");
     }
@@ -0,0 +1,50 @@
+use crate::{Base, Base58Btc, DecodeError};
+
+/// bytes in a full block
+pub const BLOCK_SIZE: usize = 8;
+
+/// chars a block of `n` bytes encodes to, indexed by `n`
+pub const ENCODED_BLOCK_SIZES: [usize; BLOCK_SIZE + 1] = [0, 2, 3, 5, 6, 7, 9, 10, 11];
+
+/// chars in a full block
+pub const ENCODED_BLOCK_SIZE: usize = ENCODED_BLOCK_SIZES[BLOCK_SIZE];
+
+/// exact chars `bytes` encodes to
+pub fn encoded_size(bytes: usize) -> usize {
+    bytes / BLOCK_SIZE * ENCODED_BLOCK_SIZE + ENCODED_BLOCK_SIZES[bytes % BLOCK_SIZE]
+}
+
+/// exact bytes `chars` decodes to, `None` if no input encodes to that many chars
+pub fn decoded_size(chars: usize) -> Option<usize> {
+    let partial = ENCODED_BLOCK_SIZES
+        .iter()
+        .position(|&size| size == chars % ENCODED_BLOCK_SIZE)?;
+
+    Some(chars / ENCODED_BLOCK_SIZE * BLOCK_SIZE + partial)
+}
+
+/// Monero flavoured [`Base58Btc`]: every 8 byte block becomes exactly 11 chars (a shorter final
+/// block uses [`ENCODED_BLOCK_SIZES`]), so the output length only depends on the input length
+/// and encoding is linear instead of quadratic.
+///
+/// This is [`Base::encode_blocks_mut`] with a block of 8.
+///
+/// output is chars written
+///
+/// ```rust
+/// use smol_base_x::monero;
+///
+/// let mut buf = [0u8; 32];
+/// let written = monero::encode_mut([0xFF; 9], &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"jpXCZedGfVQ5Q");
+/// ```
+pub fn encode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+    Base58Btc::encode_blocks_mut(input, buf, BLOCK_SIZE)
+}
+
+/// output is bytes written.
+///
+/// Blocks whose value doesn't fit in their byte size are rejected with [`DecodeError::Overflow`].
+pub fn decode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+    Base58Btc::decode_blocks_mut(input, buf, BLOCK_SIZE)
+}
@@ -0,0 +1,123 @@
+use crate::{
+    rfc4648::{self, Rfc4648},
+    Base, Base10, Base36, Base36Upper, Base58Btc, Base58Flickr, DecodeError,
+};
+
+macro_rules! multibase {
+    ($($(#[$doc:meta])* $variant:ident = $code:literal => $codec:ty,)*) => {
+        /// encodings from the [multibase table](https://github.com/multiformats/multibase/blob/master/multibase.csv)
+        /// that this crate implements
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum Multibase {
+            $($(#[$doc])* $variant,)*
+        }
+
+        impl Multibase {
+            /// every supported encoding
+            pub const ALL: &'static [Multibase] = &[$(Multibase::$variant,)*];
+
+            /// the prefix char
+            pub fn code(self) -> u8 {
+                match self {
+                    $(Multibase::$variant => $code,)*
+                }
+            }
+
+            pub fn from_code(code: u8) -> Option<Self> {
+                match code {
+                    $($code => Some(Multibase::$variant),)*
+                    _ => None,
+                }
+            }
+
+            /// encodes without the prefix
+            fn encode_raw(self, input: &[u8], buf: &mut [u8]) -> Result<usize, DecodeError> {
+                match self {
+                    $(Multibase::$variant => <$codec>::encode_mut(input, buf),)*
+                }
+            }
+
+            /// decodes without the prefix
+            fn decode_raw(self, input: &[u8], buf: &mut [u8]) -> Result<usize, DecodeError> {
+                match self {
+                    $(Multibase::$variant => <$codec>::decode_mut(input, buf),)*
+                }
+            }
+        }
+    };
+}
+
+multibase!(
+    /// bitwise, 8 symbols per byte
+    Base2 = b'0' => rfc4648::Binary,
+    /// bitwise, 8 symbols per 3 bytes
+    Base8 = b'7' => rfc4648::Octal,
+    Base10 = b'9' => Base10,
+    Base16 = b'f' => rfc4648::Base16Lower,
+    Base16Upper = b'F' => rfc4648::Base16,
+    Base32 = b'b' => rfc4648::Base32LowerNoPad,
+    Base32Upper = b'B' => rfc4648::Base32NoPad,
+    Base32Pad = b'c' => rfc4648::Base32Lower,
+    Base32PadUpper = b'C' => rfc4648::Base32,
+    Base32Hex = b'v' => rfc4648::Base32HexLowerNoPad,
+    Base32HexUpper = b'V' => rfc4648::Base32HexNoPad,
+    Base32HexPad = b't' => rfc4648::Base32HexLower,
+    Base32HexPadUpper = b'T' => rfc4648::Base32Hex,
+    Base32Z = b'h' => rfc4648::ZBase32,
+    Base36 = b'k' => Base36,
+    Base36Upper = b'K' => Base36Upper,
+    Base58Flickr = b'Z' => Base58Flickr,
+    Base58Btc = b'z' => Base58Btc,
+    Base64 = b'm' => rfc4648::Base64NoPad,
+    Base64Pad = b'M' => rfc4648::Base64,
+    Base64Url = b'u' => rfc4648::Base64UrlNoPad,
+    Base64UrlPad = b'U' => rfc4648::Base64Url,
+);
+
+impl Multibase {
+    /// writes the prefix followed by `input` in this encoding, output is chars written
+    ///
+    /// ```rust
+    /// use smol_base_x::multibase::{self, Multibase};
+    ///
+    /// let mut buf = [0u8; 32];
+    /// let written = Multibase::Base58Btc.encode_mut("yes mani !", &mut buf).unwrap();
+    /// assert_eq!(&buf[..written], b"z7paNL19xttacUY");
+    ///
+    /// let mut decoded = [0u8; 32];
+    /// let (base, len) = multibase::decode_mut(&buf[..written], &mut decoded).unwrap();
+    /// assert_eq!(base, Multibase::Base58Btc);
+    /// assert_eq!(&decoded[..len], b"yes mani !");
+    /// ```
+    pub fn encode_mut<I: AsRef<[u8]>>(
+        self,
+        input: I,
+        buf: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        let (prefix, rest) = buf.split_first_mut().ok_or(DecodeError::InvalidLength(1))?;
+        *prefix = self.code();
+
+        self.encode_raw(input.as_ref(), rest)
+            .map(|written| written + 1)
+            .map_err(|err| match err {
+                DecodeError::InvalidLength(size) => DecodeError::InvalidLength(size + 1),
+                err => err,
+            })
+    }
+}
+
+/// decodes with whichever encoding the first char names.
+///
+/// output is `(encoding, bytes written)`
+pub fn decode_mut<I: AsRef<[u8]>>(
+    input: I,
+    buf: &mut [u8],
+) -> Result<(Multibase, usize), DecodeError> {
+    let (&code, rest) = input
+        .as_ref()
+        .split_first()
+        .ok_or(DecodeError::InvalidLength(1))?;
+    let base = Multibase::from_code(code).ok_or(DecodeError::UnknownPrefix)?;
+
+    Ok((base, base.decode_raw(rest, buf)?))
+}
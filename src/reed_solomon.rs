@@ -0,0 +1,401 @@
+use core::marker::PhantomData;
+
+use crate::{Base, DecodeError};
+
+/// most parity symbols (field elements) supported, bounds the stack used while decoding
+pub const MAX_PARITY: usize = 32;
+
+/// Reed-Solomon forward error correction over the alphabet of `B`.
+///
+/// The code works over GF(p) where `p` is the smallest prime `>= BASE`. Each encoded symbol is a field element,
+/// so when `BASE` is prime every parity value is a single symbol. Otherwise parity values can be `>= BASE`
+/// and are written as two symbols each (see [`ReedSolomon::parity_width`]).
+///
+/// With `parity` parity values up to `parity / 2` wrong symbols can be corrected.
+/// Data plus parity values can be at most `p - 1` field elements long.
+///
+/// ```rust
+/// use smol_base_x::{reed_solomon::ReedSolomon, Base67};
+///
+/// let mut encoded = [0u8; 32];
+/// let written = ReedSolomon::<Base67>::encode_mut(b"hi!", 4, &mut encoded).unwrap();
+///
+/// // two symbols misread
+/// encoded[0] = b'A';
+/// encoded[written - 1] = b'A';
+///
+/// let mut decoded = [0u8; 16];
+/// let mut fixed = [0usize; 2];
+/// let (len, fixed_count) =
+///     ReedSolomon::<Base67>::decode_mut(&mut encoded[..written], 4, &mut decoded, &mut fixed).unwrap();
+///
+/// assert_eq!(&decoded[..len], b"hi!");
+/// assert_eq!(fixed_count, 2);
+/// ```
+#[derive(Debug, Default)]
+pub struct ReedSolomon<B>(PhantomData<B>);
+
+impl<B> ReedSolomon<B> {
+    /// the prime the code is computed over
+    pub fn field_size<const BASE: usize>() -> usize
+    where
+        B: Base<BASE>,
+    {
+        next_prime(BASE)
+    }
+
+    /// symbols each parity value takes up in the encoded string, 1 for prime bases otherwise 2
+    pub fn parity_width<const BASE: usize>() -> usize
+    where
+        B: Base<BASE>,
+    {
+        if next_prime(BASE) == BASE {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// [`Base::encode_mut`] followed by `parity` parity values, returning symbols written
+    pub fn encode_mut<const BASE: usize, I: AsRef<[u8]>>(
+        input: I,
+        parity: usize,
+        buf: &mut [u8],
+    ) -> Result<usize, DecodeError>
+    where
+        B: Base<BASE>,
+    {
+        let field = Field::new(next_prime(BASE));
+        let width = Self::parity_width();
+
+        if parity > MAX_PARITY {
+            return Err(DecodeError::InvalidLength(MAX_PARITY));
+        }
+
+        let written = B::encode_mut(input, buf)?;
+        if written + parity > field.p - 1 {
+            return Err(DecodeError::InvalidLength(field.p - 1));
+        }
+
+        let total = written + parity * width;
+        if total > buf.len() {
+            return Err(DecodeError::InvalidLength(total));
+        }
+
+        let mut generator = [0u32; MAX_PARITY + 1];
+        field.generator(&mut generator[..=parity]);
+
+        // remainder of data(x) * x^parity / generator(x), highest power first
+        let mut rem = [0u32; MAX_PARITY];
+        let rem = &mut rem[..parity];
+        for &ch in &buf[..written] {
+            let value = B::lookup_ascii(ch).ok_or(DecodeError::InvalidChar)? as u32;
+
+            let coef = field.add(value, rem.first().copied().unwrap_or(0));
+            for j in 0..parity {
+                let next = rem.get(j + 1).copied().unwrap_or(0);
+                rem[j] = field.sub(next, field.mul(coef, generator[j + 1]));
+            }
+        }
+
+        // codeword is data(x) * x^parity - remainder
+        for (j, &r) in rem.iter().enumerate() {
+            let value = field.sub(0, r) as usize;
+            let at = written + j * width;
+            if width == 1 {
+                buf[at] = B::ALPHABET[value];
+            } else {
+                buf[at] = B::ALPHABET[value / BASE];
+                buf[at + 1] = B::ALPHABET[value % BASE];
+            }
+        }
+
+        Ok(total)
+    }
+
+    /// corrects up to `parity / 2` wrong symbols of `encoded` in place, writing the index of each
+    /// corrected symbol into `fixed` (ascending). Returns the number of symbols fixed.
+    ///
+    /// Symbols that are not in the alphabet at all are treated as errors and corrected too.
+    /// For two symbol parity values, the index of the first symbol is reported.
+    pub fn correct_mut<const BASE: usize>(
+        encoded: &mut [u8],
+        parity: usize,
+        fixed: &mut [usize],
+    ) -> Result<usize, DecodeError>
+    where
+        B: Base<BASE>,
+    {
+        let field = Field::new(next_prime(BASE));
+        let width = Self::parity_width();
+
+        if parity > MAX_PARITY {
+            return Err(DecodeError::InvalidLength(MAX_PARITY));
+        }
+        if encoded.len() < parity * width {
+            return Err(DecodeError::InvalidLength(parity * width));
+        }
+
+        let data_len = encoded.len() - parity * width;
+        let n = data_len + parity;
+        if n > field.p - 1 {
+            return Err(DecodeError::InvalidLength(field.p - 1));
+        }
+
+        // field element at codeword index `i`, where the power of x is `n - 1 - i`
+        let value = |encoded: &[u8], i: usize| -> u32 {
+            if i < data_len {
+                return B::lookup_ascii(encoded[i]).unwrap_or(0) as u32;
+            }
+            let at = data_len + (i - data_len) * width;
+            if width == 1 {
+                return B::lookup_ascii(encoded[at]).unwrap_or(0) as u32;
+            }
+            match (
+                B::lookup_ascii(encoded[at]),
+                B::lookup_ascii(encoded[at + 1]),
+            ) {
+                (Some(hi), Some(lo)) if hi * BASE + lo < field.p => (hi * BASE + lo) as u32,
+                _ => 0,
+            }
+        };
+
+        let mut syndromes = [0u32; MAX_PARITY];
+        let syndromes = &mut syndromes[..parity];
+        let mut clean = true;
+        for (j, s) in syndromes.iter_mut().enumerate() {
+            let x = field.pow(field.alpha, j as u32 + 1);
+            *s = (0..n).fold(0, |acc, i| field.add(field.mul(acc, x), value(encoded, i)));
+            clean &= *s == 0;
+        }
+
+        if clean {
+            return Ok(0);
+        }
+
+        // Berlekamp-Massey for the error locator
+        let mut locator = [0u32; MAX_PARITY + 1];
+        let mut prev = [0u32; MAX_PARITY + 1];
+        locator[0] = 1;
+        prev[0] = 1;
+        let mut errors = 0;
+        let mut shift = 1;
+        let mut prev_discrepancy = 1;
+
+        for step in 0..parity {
+            let mut discrepancy = syndromes[step];
+            for i in 1..=errors {
+                discrepancy = field.add(discrepancy, field.mul(locator[i], syndromes[step - i]));
+            }
+
+            if discrepancy == 0 {
+                shift += 1;
+                continue;
+            }
+
+            let coef = field.mul(discrepancy, field.inv(prev_discrepancy));
+            let snapshot = locator;
+            for i in shift..=parity {
+                locator[i] = field.sub(locator[i], field.mul(coef, prev[i - shift]));
+            }
+
+            if 2 * errors <= step {
+                errors = step + 1 - errors;
+                prev = snapshot;
+                prev_discrepancy = discrepancy;
+                shift = 1;
+            } else {
+                shift += 1;
+            }
+        }
+
+        if 2 * errors > parity {
+            return Err(DecodeError::Uncorrectable);
+        }
+        if errors > fixed.len() {
+            return Err(DecodeError::InvalidLength(errors));
+        }
+
+        // Chien search and Forney for each error position
+        let mut corrections = [(0usize, 0u32); MAX_PARITY / 2];
+        let mut found = 0;
+        for i in 0..n {
+            let x_inv = field.inv(field.pow(field.alpha, (n - 1 - i) as u32));
+            if field.eval(&locator[..=errors], x_inv) != 0 {
+                continue;
+            }
+            if found == errors {
+                return Err(DecodeError::Uncorrectable);
+            }
+
+            // error evaluator: syndromes(x) * locator(x) mod x^parity, at x_inv
+            let mut omega = 0;
+            let mut x_pow = 1;
+            for d in 0..parity {
+                let mut coef = 0;
+                for j in 0..=d.min(errors) {
+                    coef = field.add(coef, field.mul(locator[j], syndromes[d - j]));
+                }
+                omega = field.add(omega, field.mul(coef, x_pow));
+                x_pow = field.mul(x_pow, x_inv);
+            }
+
+            // formal derivative of the locator, at x_inv
+            let mut derivative = 0;
+            let mut x_pow = 1;
+            for (j, &coef) in locator.iter().enumerate().take(errors + 1).skip(1) {
+                derivative = field.add(derivative, field.mul(field.mul(j as u32, coef), x_pow));
+                x_pow = field.mul(x_pow, x_inv);
+            }
+            if derivative == 0 {
+                return Err(DecodeError::Uncorrectable);
+            }
+
+            let magnitude = field.sub(0, field.mul(omega, field.inv(derivative)));
+            let corrected = field.sub(value(encoded, i), magnitude);
+            // a data symbol outside of the alphabet means the decoder guessed wrong
+            if i < data_len && corrected as usize >= BASE {
+                return Err(DecodeError::Uncorrectable);
+            }
+
+            corrections[found] = (i, corrected);
+            found += 1;
+        }
+
+        if found != errors {
+            return Err(DecodeError::Uncorrectable);
+        }
+
+        for (slot, &(i, corrected)) in fixed.iter_mut().zip(&corrections[..found]) {
+            let corrected = corrected as usize;
+            if i < data_len {
+                encoded[i] = B::ALPHABET[corrected];
+                *slot = i;
+            } else {
+                let at = data_len + (i - data_len) * width;
+                if width == 1 {
+                    encoded[at] = B::ALPHABET[corrected];
+                } else {
+                    encoded[at] = B::ALPHABET[corrected / BASE];
+                    encoded[at + 1] = B::ALPHABET[corrected % BASE];
+                }
+                *slot = at;
+            }
+        }
+
+        Ok(found)
+    }
+
+    /// [`ReedSolomon::correct_mut`] then [`Base::decode_mut`] of the data symbols.
+    ///
+    /// output is `(bytes written, symbols fixed)`
+    pub fn decode_mut<const BASE: usize>(
+        input: &mut [u8],
+        parity: usize,
+        buf: &mut [u8],
+        fixed: &mut [usize],
+    ) -> Result<(usize, usize), DecodeError>
+    where
+        B: Base<BASE>,
+    {
+        let fixed_count = Self::correct_mut(input, parity, fixed)?;
+
+        let data_len = input.len() - parity * Self::parity_width();
+        let written = B::decode_mut(&input[..data_len], buf)?;
+
+        Ok((written, fixed_count))
+    }
+}
+
+/// arithmetic mod a small prime
+struct Field {
+    p: usize,
+    /// primitive element
+    alpha: u32,
+}
+
+impl Field {
+    fn new(p: usize) -> Self {
+        let mut field = Field { p, alpha: 1 };
+        field.alpha = (1..p as u32).find(|&g| field.is_primitive(g)).unwrap_or(1);
+        field
+    }
+
+    /// `g` generates the whole multiplicative group when `g^((p-1)/q) != 1` for each prime factor `q` of `p - 1`
+    fn is_primitive(&self, g: u32) -> bool {
+        let order = self.p as u32 - 1;
+        let mut rest = order;
+        let mut q = 2;
+        while rest > 1 {
+            if rest.is_multiple_of(q) {
+                if self.pow(g, order / q) == 1 {
+                    return false;
+                }
+                while rest.is_multiple_of(q) {
+                    rest /= q;
+                }
+            }
+            q += 1;
+        }
+        true
+    }
+
+    fn add(&self, a: u32, b: u32) -> u32 {
+        (a + b) % self.p as u32
+    }
+
+    fn sub(&self, a: u32, b: u32) -> u32 {
+        (a + self.p as u32 - b) % self.p as u32
+    }
+
+    fn mul(&self, a: u32, b: u32) -> u32 {
+        (a * b) % self.p as u32
+    }
+
+    fn pow(&self, mut a: u32, mut exp: u32) -> u32 {
+        let mut out = 1;
+        while exp > 0 {
+            if exp & 1 == 1 {
+                out = self.mul(out, a);
+            }
+            a = self.mul(a, a);
+            exp >>= 1;
+        }
+        out
+    }
+
+    fn inv(&self, a: u32) -> u32 {
+        self.pow(a, self.p as u32 - 2)
+    }
+
+    /// evaluates a polynomial stored lowest power first
+    fn eval(&self, poly: &[u32], x: u32) -> u32 {
+        poly.iter()
+            .rev()
+            .fold(0, |acc, &coef| self.add(self.mul(acc, x), coef))
+    }
+
+    /// `(x - alpha^1)..(x - alpha^k)` stored highest power first, `out` is `k + 1` long
+    fn generator(&self, out: &mut [u32]) {
+        out.fill(0);
+        out[0] = 1;
+        for j in 1..out.len() {
+            let root = self.pow(self.alpha, j as u32);
+            for i in (1..=j).rev() {
+                out[i] = self.sub(out[i], self.mul(root, out[i - 1]));
+            }
+        }
+    }
+}
+
+/// smallest prime `>= n`
+fn next_prime(n: usize) -> usize {
+    let mut candidate = n.max(2);
+    while (2..candidate)
+        .take_while(|d| d * d <= candidate)
+        .any(|d| candidate.is_multiple_of(d))
+    {
+        candidate += 1;
+    }
+    candidate
+}
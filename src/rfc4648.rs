@@ -0,0 +1,244 @@
+use crate::{util::gen_lut, DecodeError};
+
+/// ## Bit-oriented encodings from RFC 4648
+///
+/// Unlike [`crate::Base`] the input isn't treated as one big number, each symbol carries exactly
+/// `log2(BASE)` bits of the input, most significant bit first. `BASE` has to be a power of two.
+pub trait Rfc4648<const BASE: usize> {
+    const ALPHABET: [u8; BASE];
+
+    const LUT: [i8; 256] = gen_lut(&Self::ALPHABET);
+
+    /// `Some(b'=')` for variants that pad the output to a whole block
+    const PADDING: Option<u8>;
+
+    /// bits each symbol carries
+    const BITS: usize = BASE.trailing_zeros() as usize;
+
+    /// symbols in a block, the smallest run of symbols that lines up with whole bytes
+    const BLOCK_SYMBOLS: usize = lcm(8, Self::BITS) / Self::BITS;
+
+    /// bytes in a block
+    const BLOCK_BYTES: usize = lcm(8, Self::BITS) / 8;
+
+    /// exact number of symbols (and padding) `bytes` encodes to
+    fn encoded_len(bytes: usize) -> usize {
+        match Self::PADDING {
+            Some(_) => bytes.div_ceil(Self::BLOCK_BYTES) * Self::BLOCK_SYMBOLS,
+            None => (bytes * 8).div_ceil(Self::BITS),
+        }
+    }
+
+    /// upper bound of the bytes `symbols` decodes to, exact when there is no padding
+    fn decoded_len(symbols: usize) -> usize {
+        symbols * Self::BITS / 8
+    }
+
+    /// output is symbols written, `buf` must fit [`Rfc4648::encoded_len`]
+    ///
+    /// ```rust
+    /// use smol_base_x::rfc4648::*;
+    ///
+    /// let mut buf = [0u8; 16];
+    /// let written = Base64::encode_mut("foob", &mut buf).unwrap();
+    /// assert_eq!(&buf[..written], b"Zm9vYg==");
+    ///
+    /// let written = Base64NoPad::encode_mut("foob", &mut buf).unwrap();
+    /// assert_eq!(&buf[..written], b"Zm9vYg");
+    /// ```
+    fn encode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        let input = input.as_ref();
+
+        let size = Self::encoded_len(input.len());
+        if size > buf.len() {
+            return Err(DecodeError::InvalidLength(size));
+        }
+
+        let mask = (1 << Self::BITS) - 1;
+        let mut acc = 0u16;
+        let mut bits = 0;
+        let mut written = 0;
+
+        for &byte in input {
+            acc = (acc << 8) | byte as u16;
+            bits += 8;
+            while bits >= Self::BITS {
+                bits -= Self::BITS;
+                buf[written] = Self::ALPHABET[((acc >> bits) & mask) as usize];
+                written += 1;
+            }
+        }
+
+        // left over bits are padded with zeros on the right
+        if bits > 0 {
+            buf[written] = Self::ALPHABET[((acc << (Self::BITS - bits)) & mask) as usize];
+            written += 1;
+        }
+
+        if let Some(pad) = Self::PADDING {
+            buf[written..size].fill(pad);
+            written = size;
+        }
+
+        Ok(written)
+    }
+
+    /// output is bytes written.
+    ///
+    /// Decoding is strict: padding must be present (or absent) as the variant says,
+    /// and the unused bits of the last symbol must be zero.
+    fn decode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<usize, DecodeError> {
+        let mut input = input.as_ref();
+
+        if let Some(pad) = Self::PADDING {
+            if input.len() % Self::BLOCK_SYMBOLS != 0 {
+                return Err(DecodeError::InvalidLength(
+                    input.len().next_multiple_of(Self::BLOCK_SYMBOLS),
+                ));
+            }
+
+            let pads = input.iter().rev().take_while(|&&ch| ch == pad).count();
+            if pads >= Self::BLOCK_SYMBOLS {
+                return Err(DecodeError::InvalidPadding);
+            }
+            input = &input[..input.len() - pads];
+        }
+
+        // a trailing partial block has to carry at least one whole byte,
+        // which also rules out padding that is too long for its block
+        let partial = input.len() % Self::BLOCK_SYMBOLS;
+        if partial != 0 && (partial * Self::BITS) % 8 >= Self::BITS {
+            return Err(match Self::PADDING {
+                Some(_) => DecodeError::InvalidPadding,
+                None => DecodeError::InvalidLength(input.len() + 1),
+            });
+        }
+
+        let size = Self::decoded_len(input.len());
+        if size > buf.len() {
+            return Err(DecodeError::InvalidLength(size));
+        }
+
+        let mut acc = 0u16;
+        let mut bits = 0;
+        let mut written = 0;
+
+        for &ch in input {
+            let value = Self::lookup_ascii(ch).ok_or(DecodeError::InvalidChar)?;
+
+            acc = (acc << Self::BITS) | value as u16;
+            bits += Self::BITS;
+            if bits >= 8 {
+                bits -= 8;
+                buf[written] = (acc >> bits) as u8;
+                written += 1;
+            }
+        }
+
+        if acc & ((1 << bits) - 1) != 0 {
+            return Err(DecodeError::NonCanonical);
+        }
+
+        Ok(written)
+    }
+
+    /// Lookup the value for the current char index
+    fn lookup_ascii(ch: u8) -> Option<usize> {
+        match Self::LUT[ch as usize] {
+            -1 => None,
+            i => Some(i as usize),
+        }
+    }
+}
+
+const fn lcm(a: usize, b: usize) -> usize {
+    let (mut x, mut y) = (a, b);
+    while y != 0 {
+        let t = x % y;
+        x = y;
+        y = t;
+    }
+    a / x * b
+}
+
+macro_rules! rfc4648 {
+    ($(#[$doc:meta])* $name:ident, $len:literal $alph:literal, $pad:expr) => {
+        $(#[$doc])*
+        #[derive(Debug, Default)]
+        pub struct $name;
+
+        impl Rfc4648<$len> for $name {
+            const ALPHABET: [u8; $len] = *$alph;
+            const PADDING: Option<u8> = $pad;
+        }
+    };
+}
+
+rfc4648!(
+    /// RFC 4648 §8, upper case hex
+    Base16, 16 b"0123456789ABCDEF", None
+);
+rfc4648!(
+    /// RFC 4648 §8 in lower case, as used by multibase
+    Base16Lower, 16 b"0123456789abcdef", None
+);
+rfc4648!(
+    /// RFC 4648 §6
+    Base32, 32 b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567", Some(b'=')
+);
+rfc4648!(
+    /// RFC 4648 §6 without padding
+    Base32NoPad, 32 b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567", None
+);
+rfc4648!(
+    /// RFC 4648 §6 in lower case
+    Base32Lower, 32 b"abcdefghijklmnopqrstuvwxyz234567", Some(b'=')
+);
+rfc4648!(
+    /// RFC 4648 §6 in lower case without padding
+    Base32LowerNoPad, 32 b"abcdefghijklmnopqrstuvwxyz234567", None
+);
+rfc4648!(
+    /// RFC 4648 §7, "extended hex" alphabet that keeps sort order
+    Base32Hex, 32 b"0123456789ABCDEFGHIJKLMNOPQRSTUV", Some(b'=')
+);
+rfc4648!(
+    /// RFC 4648 §7 without padding
+    Base32HexNoPad, 32 b"0123456789ABCDEFGHIJKLMNOPQRSTUV", None
+);
+rfc4648!(
+    /// RFC 4648 §7 in lower case
+    Base32HexLower, 32 b"0123456789abcdefghijklmnopqrstuv", Some(b'=')
+);
+rfc4648!(
+    /// RFC 4648 §7 in lower case without padding
+    Base32HexLowerNoPad, 32 b"0123456789abcdefghijklmnopqrstuv", None
+);
+rfc4648!(
+    /// RFC 4648 §4
+    Base64, 64 b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/", Some(b'=')
+);
+rfc4648!(
+    /// RFC 4648 §4 without padding
+    Base64NoPad, 64 b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/", None
+);
+rfc4648!(
+    /// RFC 4648 §5, URL and filename safe
+    Base64Url, 64 b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_", Some(b'=')
+);
+rfc4648!(
+    /// RFC 4648 §5 without padding
+    Base64UrlNoPad, 64 b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_", None
+);
+rfc4648!(
+    /// z-base-32, a human oriented permutation of base32 that is never padded
+    ZBase32, 32 b"ybndrfg8ejkmcpqxot1uwisza345h769", None
+);
+rfc4648!(
+    /// one bit per symbol, so unlike [`crate::Base2`] every byte is always 8 symbols
+    Binary, 2 b"01", None
+);
+rfc4648!(
+    /// three bits per symbol, unlike [`crate::Base8`] 3 bytes are always 8 symbols
+    Octal, 8 b"01234567", None
+);
@@ -0,0 +1,69 @@
+use crate::{Base, Base57, Base58Btc, Base62, DecodeError, StackStr};
+
+/// chars every encoding here pads a UUID to, the widest 128 bits gets in base 57
+pub const ENCODED_LEN: usize = 22;
+
+/// encodes a UUID the way python's `shortuuid.encode` does: [`Base57`], most significant
+/// symbol first, left padded with `2` to 22 chars.
+///
+/// ```rust
+/// use smol_base_x::shortuuid;
+///
+/// let uuid = 0x6ca4f0f8_2508_4bac_b8f1_5d1e3da2247au128.to_be_bytes();
+/// assert_eq!(shortuuid::encode(uuid).as_str(), "MLpZDiEXM4VsUryR9oE8uc");
+/// assert_eq!(shortuuid::decode("MLpZDiEXM4VsUryR9oE8uc").unwrap(), uuid);
+/// ```
+pub fn encode(uuid: [u8; 16]) -> StackStr<ENCODED_LEN> {
+    encode_as::<57, Base57>(uuid)
+}
+
+/// inverse of [`encode`], only the 22 char form is accepted
+pub fn decode<I: AsRef<[u8]>>(input: I) -> Result<[u8; 16], DecodeError> {
+    decode_as::<57, Base57>(input.as_ref())
+}
+
+/// fixed 22 char [`Base58Btc`] form, left padded with `1`
+pub fn encode_base58(uuid: [u8; 16]) -> StackStr<ENCODED_LEN> {
+    encode_as::<58, Base58Btc>(uuid)
+}
+
+pub fn decode_base58<I: AsRef<[u8]>>(input: I) -> Result<[u8; 16], DecodeError> {
+    decode_as::<58, Base58Btc>(input.as_ref())
+}
+
+/// fixed 22 char [`Base62`] form, left padded with `0`
+pub fn encode_base62(uuid: [u8; 16]) -> StackStr<ENCODED_LEN> {
+    encode_as::<62, Base62>(uuid)
+}
+
+pub fn decode_base62<I: AsRef<[u8]>>(input: I) -> Result<[u8; 16], DecodeError> {
+    decode_as::<62, Base62>(input.as_ref())
+}
+
+/// [`encode`] for a [`uuid::Uuid`]
+#[cfg(feature = "uuid")]
+pub fn encode_uuid(uuid: &uuid::Uuid) -> StackStr<ENCODED_LEN> {
+    encode(*uuid.as_bytes())
+}
+
+/// [`decode`] into a [`uuid::Uuid`]
+#[cfg(feature = "uuid")]
+pub fn decode_uuid<I: AsRef<[u8]>>(input: I) -> Result<uuid::Uuid, DecodeError> {
+    decode(input).map(uuid::Uuid::from_bytes)
+}
+
+fn encode_as<const BASE: usize, B: Base<BASE>>(uuid: [u8; 16]) -> StackStr<ENCODED_LEN> {
+    let mut buf = [0u8; ENCODED_LEN];
+    B::encode_fixed_mut(uuid, &mut buf).expect("16 bytes always fit in 22 chars of base 57 and up");
+    StackStr::from_tail(buf, ENCODED_LEN)
+}
+
+fn decode_as<const BASE: usize, B: Base<BASE>>(input: &[u8]) -> Result<[u8; 16], DecodeError> {
+    if input.len() != ENCODED_LEN {
+        return Err(DecodeError::InvalidLength(ENCODED_LEN));
+    }
+
+    let mut uuid = [0u8; 16];
+    B::decode_fixed_mut(input, &mut uuid)?;
+    Ok(uuid)
+}
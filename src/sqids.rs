@@ -0,0 +1,297 @@
+use crate::{Base, DecodeError};
+
+/// the alphabet [`Sqids::default`] uses, same as the reference implementations
+pub const DEFAULT_ALPHABET: &[u8; 62] =
+    b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ0123456789";
+
+/// alphabets are ASCII, so they can't be any longer than this
+pub const MAX_ALPHABET_LEN: usize = 128;
+
+/// [Sqids](https://sqids.org) encoder: lists of integers become short strings that don't look sequential.
+///
+/// The alphabet is shuffled up front (and again between numbers), so it works as the "salt".
+/// Output matches the reference implementations given the same alphabet, minimum length and blocklist.
+/// The reference default blocklist isn't bundled, pass one in with [`Sqids::with_blocklist`] to match it.
+///
+/// ```rust
+/// use smol_base_x::sqids::Sqids;
+///
+/// let sqids = Sqids::default().with_min_length(10);
+///
+/// let mut buf = [0u8; 32];
+/// let written = sqids.encode_mut(&[1, 2, 3], &mut buf).unwrap();
+/// assert_eq!(&buf[..written], b"86Rf07xd4z");
+///
+/// let mut numbers = [0u64; 4];
+/// let len = sqids.decode_mut(&buf[..written], &mut numbers).unwrap();
+/// assert_eq!(&numbers[..len], &[1, 2, 3]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Sqids<'a> {
+    /// shuffled alphabet, only the first `len` are used
+    alphabet: [u8; MAX_ALPHABET_LEN],
+    len: usize,
+    min_length: u8,
+    blocklist: &'a [&'a str],
+}
+
+impl Default for Sqids<'_> {
+    fn default() -> Self {
+        Self::new(DEFAULT_ALPHABET).expect("default alphabet is valid")
+    }
+}
+
+impl<'a> Sqids<'a> {
+    /// runtime alphabet, it must be at least 3 unique ASCII chars
+    pub fn new(alphabet: &[u8]) -> Result<Self, DecodeError> {
+        if alphabet.len() < 3 {
+            return Err(DecodeError::InvalidLength(3));
+        }
+        if alphabet.len() > MAX_ALPHABET_LEN || !alphabet.is_ascii() {
+            return Err(DecodeError::InvalidChar);
+        }
+
+        let mut seen = [false; MAX_ALPHABET_LEN];
+        for &ch in alphabet {
+            if seen[ch as usize] {
+                return Err(DecodeError::InvalidChar);
+            }
+            seen[ch as usize] = true;
+        }
+
+        let mut shuffled = [0u8; MAX_ALPHABET_LEN];
+        shuffled[..alphabet.len()].copy_from_slice(alphabet);
+        shuffle(&mut shuffled[..alphabet.len()]);
+
+        Ok(Sqids {
+            alphabet: shuffled,
+            len: alphabet.len(),
+            min_length: 0,
+            blocklist: &[],
+        })
+    }
+
+    /// uses the alphabet of any [`Base`]
+    pub fn from_base<const BASE: usize, B: Base<BASE>>() -> Result<Self, DecodeError> {
+        Self::new(&B::ALPHABET)
+    }
+
+    /// pads every output to at least `min_length` chars
+    pub fn with_min_length(mut self, min_length: u8) -> Self {
+        self.min_length = min_length;
+        self
+    }
+
+    /// outputs containing any of these words (case-insensitive) are re-generated.
+    ///
+    /// Words shorter than 3 chars or using chars outside the alphabet are ignored, as in the spec.
+    pub fn with_blocklist(mut self, blocklist: &'a [&'a str]) -> Self {
+        self.blocklist = blocklist;
+        self
+    }
+
+    /// output is chars written, no numbers encodes to an empty string.
+    ///
+    /// Errors with [`DecodeError::Blocked`] if every variation of the output hits the blocklist.
+    pub fn encode_mut(&self, numbers: &[u64], buf: &mut [u8]) -> Result<usize, DecodeError> {
+        if numbers.is_empty() {
+            return Ok(0);
+        }
+
+        for increment in 0..=self.len {
+            let written = self.encode_attempt(numbers, increment, buf)?;
+            if !self.is_blocked(&buf[..written]) {
+                return Ok(written);
+            }
+        }
+
+        Err(DecodeError::Blocked)
+    }
+
+    /// output is numbers written, an empty `id` has no numbers.
+    pub fn decode_mut<I: AsRef<[u8]>>(
+        &self,
+        id: I,
+        numbers: &mut [u64],
+    ) -> Result<usize, DecodeError> {
+        let id = id.as_ref();
+        let alphabet = &self.alphabet[..self.len];
+
+        let Some((&prefix, mut rest)) = id.split_first() else {
+            return Ok(0);
+        };
+        if !id.iter().all(|ch| alphabet.contains(ch)) {
+            return Err(DecodeError::InvalidChar);
+        }
+
+        let offset = position(alphabet, prefix);
+        let mut buf = [0u8; MAX_ALPHABET_LEN];
+        let working = &mut buf[..self.len];
+        working.copy_from_slice(alphabet);
+        working.rotate_left(offset);
+        working.reverse();
+
+        let mut count = 0;
+        while !rest.is_empty() {
+            let separator = working[0];
+            let (chunk, tail) = match rest.iter().position(|&ch| ch == separator) {
+                Some(at) => (&rest[..at], Some(&rest[at + 1..])),
+                None => (rest, None),
+            };
+
+            // an empty chunk means the rest is padding
+            if chunk.is_empty() {
+                break;
+            }
+
+            if count == numbers.len() {
+                return Err(DecodeError::InvalidLength(count + 1));
+            }
+            numbers[count] = to_number(chunk, &working[1..])?;
+            count += 1;
+
+            if let Some(tail) = tail {
+                shuffle(working);
+                rest = tail;
+            } else {
+                rest = &[];
+            }
+        }
+
+        Ok(count)
+    }
+
+    fn encode_attempt(
+        &self,
+        numbers: &[u64],
+        increment: usize,
+        buf: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        let alphabet = &self.alphabet[..self.len];
+        let len = self.len;
+
+        let offset = numbers
+            .iter()
+            .enumerate()
+            .fold(numbers.len(), |acc, (i, &n)| {
+                acc + i + alphabet[(n % len as u64) as usize] as usize
+            });
+        let offset = (offset % len + increment) % len;
+
+        let mut working = [0u8; MAX_ALPHABET_LEN];
+        let working = &mut working[..len];
+        working.copy_from_slice(alphabet);
+        working.rotate_left(offset);
+
+        let mut out = Output { buf, written: 0 };
+        out.push(&[working[0]])?;
+        working.reverse();
+
+        for (i, &n) in numbers.iter().enumerate() {
+            out.push_number(n, &working[1..])?;
+
+            if i + 1 < numbers.len() {
+                out.push(&[working[0]])?;
+                shuffle(working);
+            }
+        }
+
+        let min_length = self.min_length as usize;
+        if min_length > out.written {
+            out.push(&[working[0]])?;
+            while min_length > out.written {
+                shuffle(working);
+                let take = (min_length - out.written).min(len);
+                out.push(&working[..take])?;
+            }
+        }
+
+        Ok(out.written)
+    }
+
+    fn is_blocked(&self, id: &[u8]) -> bool {
+        let alphabet = &self.alphabet[..self.len];
+
+        self.blocklist
+            .iter()
+            .map(|word| word.as_bytes())
+            .filter(|word| {
+                word.len() >= 3
+                    && word
+                        .iter()
+                        .all(|ch| alphabet.iter().any(|a| a.eq_ignore_ascii_case(ch)))
+            })
+            .any(|word| {
+                if word.len() > id.len() {
+                    false
+                } else if id.len() <= 3 || word.len() <= 3 {
+                    id.eq_ignore_ascii_case(word)
+                } else if word.iter().any(u8::is_ascii_digit) {
+                    id[..word.len()].eq_ignore_ascii_case(word)
+                        || id[id.len() - word.len()..].eq_ignore_ascii_case(word)
+                } else {
+                    id.windows(word.len())
+                        .any(|window| window.eq_ignore_ascii_case(word))
+                }
+            })
+    }
+}
+
+/// writes into the caller's buffer, erroring instead of panicking when it's full
+struct Output<'b> {
+    buf: &'b mut [u8],
+    written: usize,
+}
+
+impl Output<'_> {
+    fn push(&mut self, chars: &[u8]) -> Result<(), DecodeError> {
+        let end = self.written + chars.len();
+        if end > self.buf.len() {
+            return Err(DecodeError::InvalidLength(end));
+        }
+        self.buf[self.written..end].copy_from_slice(chars);
+        self.written = end;
+        Ok(())
+    }
+
+    /// `n` in the base of `alphabet`, most significant first
+    fn push_number(&mut self, mut n: u64, alphabet: &[u8]) -> Result<(), DecodeError> {
+        // base 2 is the smallest an alphabet of 3 leaves after taking out the separator
+        let mut digits = [0u8; 64];
+        let mut len = 0;
+        loop {
+            digits[63 - len] = alphabet[(n % alphabet.len() as u64) as usize];
+            len += 1;
+            n /= alphabet.len() as u64;
+            if n == 0 {
+                break;
+            }
+        }
+        self.push(&digits[64 - len..])
+    }
+}
+
+fn to_number(chunk: &[u8], alphabet: &[u8]) -> Result<u64, DecodeError> {
+    chunk.iter().try_fold(0u64, |acc, &ch| {
+        acc.checked_mul(alphabet.len() as u64)
+            .and_then(|acc| acc.checked_add(position(alphabet, ch) as u64))
+            .ok_or(DecodeError::Overflow)
+    })
+}
+
+/// callers have already checked `ch` is in `alphabet`
+fn position(alphabet: &[u8], ch: u8) -> usize {
+    alphabet.iter().position(|&a| a == ch).unwrap_or(0)
+}
+
+/// the spec's deterministic shuffle, which depends only on the alphabet
+fn shuffle(chars: &mut [u8]) {
+    let len = chars.len();
+    let (mut i, mut j) = (0, len - 1);
+    while j > 0 {
+        let r = (i * j + chars[i] as usize + chars[j] as usize) % len;
+        chars.swap(i, r);
+        i += 1;
+        j -= 1;
+    }
+}
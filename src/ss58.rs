@@ -0,0 +1,120 @@
+use crate::{base::encode_bytes, blake2b::Blake2b, Base, Base58Btc, DecodeError};
+
+/// largest network prefix, anything above 63 takes two bytes
+pub const MAX_PREFIX: u16 = 16383;
+
+/// Substrate's SS58 format: `prefix || payload || checksum` in [`Base58Btc`], where the checksum is
+/// the start of `Blake2b-512("SS58PRE" || prefix || payload)`.
+///
+/// 32 and 33 byte payloads (public keys) get a 2 byte checksum, 1, 2, 4 and 8 byte payloads
+/// (account indices) get 1 byte. Output is chars written.
+///
+/// ```rust
+/// use smol_base_x::ss58;
+///
+/// // Alice's well known dev key on the generic substrate prefix
+/// let alice = "5GrwvaEF5zXb26Fz9rcQpDWS57CtERHpNehXCPcNoHGKutQY";
+///
+/// let mut key = [0u8; 36];
+/// let (prefix, len) = ss58::decode_mut(alice, &mut key).unwrap();
+/// assert_eq!((prefix, len), (42, 32));
+/// assert_eq!(key[..4], [0xd4, 0x35, 0x93, 0xc7]);
+///
+/// let mut buf = [0u8; 64];
+/// let written = ss58::encode_mut(prefix, &key[..len], &mut buf).unwrap();
+/// assert_eq!(&buf[..written], alice.as_bytes());
+/// ```
+pub fn encode_mut(prefix: u16, payload: &[u8], buf: &mut [u8]) -> Result<usize, DecodeError> {
+    let checksum_len = checksum_len(payload.len()).ok_or(DecodeError::InvalidLength(32))?;
+    let (prefix, prefix_len) = encode_prefix(prefix)?;
+    let prefix = &prefix[..prefix_len];
+
+    let checksum = checksum(prefix, payload);
+    let bytes = prefix
+        .iter()
+        .chain(payload)
+        .chain(&checksum[..checksum_len])
+        .copied();
+
+    encode_bytes(
+        &Base58Btc::ALPHABET,
+        bytes,
+        prefix_len + payload.len() + checksum_len,
+        buf,
+    )
+}
+
+/// decodes an SS58 address and verifies its checksum.
+///
+/// output is `(prefix, payload bytes written)`, the payload is moved to the start of `buf`.
+/// `buf` needs room for the prefix and checksum as well (4 bytes more than the payload is always enough).
+pub fn decode_mut<I: AsRef<[u8]>>(input: I, buf: &mut [u8]) -> Result<(u16, usize), DecodeError> {
+    let written = Base58Btc::decode_mut(input, buf)?;
+    let data = &buf[..written];
+
+    let (prefix, prefix_len) = match *data {
+        [first @ 0..=63, ..] => (first as u16, 1),
+        [first, ..] if first >= 128 => return Err(DecodeError::UnknownPrefix),
+        [first, second, ..] => {
+            let lower = (first << 2) | (second >> 6);
+            let upper = second & 0b0011_1111;
+            let prefix = lower as u16 | (upper as u16) << 8;
+            // small prefixes have to use the one byte form
+            if prefix < 64 {
+                return Err(DecodeError::NonCanonical);
+            }
+            (prefix, 2)
+        }
+        _ => return Err(DecodeError::InvalidLength(3)),
+    };
+
+    let rest = written - prefix_len;
+    let checksum_len = match rest {
+        34 | 35 => 2,
+        2 | 3 | 5 | 9 => 1,
+        _ => return Err(DecodeError::InvalidLength(prefix_len + 34)),
+    };
+    let payload_len = rest - checksum_len;
+
+    let (body, expected) = data.split_at(prefix_len + payload_len);
+    let (prefix_bytes, payload) = body.split_at(prefix_len);
+    if checksum(prefix_bytes, payload)[..checksum_len] != *expected {
+        return Err(DecodeError::ChecksumMismatch);
+    }
+
+    buf.copy_within(prefix_len..prefix_len + payload_len, 0);
+
+    Ok((prefix, payload_len))
+}
+
+/// checksum bytes for a payload of `len`, `None` if SS58 has no address of that size
+fn checksum_len(len: usize) -> Option<usize> {
+    match len {
+        32 | 33 => Some(2),
+        1 | 2 | 4 | 8 => Some(1),
+        _ => None,
+    }
+}
+
+/// output is `(prefix bytes, length used)`
+fn encode_prefix(prefix: u16) -> Result<([u8; 2], usize), DecodeError> {
+    match prefix {
+        0..=63 => Ok(([prefix as u8, 0], 1)),
+        64..=MAX_PREFIX => {
+            // the low 6 bits of the first byte and the high 2 bits of the second carry the
+            // lower byte of the prefix, the rest of the second byte the upper 6 bits
+            let first = ((prefix & 0b1111_1100) >> 2) as u8 | 0b0100_0000;
+            let second = (prefix >> 8) as u8 | ((prefix & 0b11) << 6) as u8;
+            Ok(([first, second], 2))
+        }
+        _ => Err(DecodeError::Overflow),
+    }
+}
+
+fn checksum(prefix: &[u8], payload: &[u8]) -> [u8; 64] {
+    let mut hasher = Blake2b::new();
+    hasher.update(b"SS58PRE");
+    hasher.update(prefix);
+    hasher.update(payload);
+    hasher.finalize()
+}
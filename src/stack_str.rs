@@ -0,0 +1,74 @@
+use core::{fmt, ops::Deref};
+
+/// fixed capacity string on the stack, returned by the integer encoders like [`crate::Base::encode_u64`].
+///
+/// Only ever holds alphabet symbols, so it is always ASCII.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct StackStr<const CAP: usize> {
+    bytes: [u8; CAP],
+    len: usize,
+}
+
+impl<const CAP: usize> StackStr<CAP> {
+    /// takes the last `len` bytes of `bytes`, which must be ASCII
+    pub(crate) fn from_tail(bytes: [u8; CAP], len: usize) -> Self {
+        debug_assert!(bytes.is_ascii());
+
+        let mut out = [0u8; CAP];
+        out[..len].copy_from_slice(&bytes[CAP - len..]);
+        StackStr { bytes: out, len }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    pub fn as_str(&self) -> &str {
+        // only ever filled from ASCII alphabets
+        core::str::from_utf8(self.as_bytes()).unwrap_or_default()
+    }
+}
+
+impl<const CAP: usize> Deref for StackStr<CAP> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const CAP: usize> AsRef<[u8]> for StackStr<CAP> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const CAP: usize> AsRef<str> for StackStr<CAP> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const CAP: usize> PartialOrd for StackStr<CAP> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const CAP: usize> Ord for StackStr<CAP> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_bytes().cmp(other.as_bytes())
+    }
+}
+
+impl<const CAP: usize> fmt::Display for StackStr<CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl<const CAP: usize> fmt::Debug for StackStr<CAP> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
@@ -0,0 +1,101 @@
+use core::fmt;
+
+use crate::{Base, Base32Crockford, DecodeError, SortedBase, StackStr};
+
+/// bytes in a ULID, a 6 byte millisecond timestamp and 10 random bytes
+pub const BYTES: usize = 16;
+
+/// chars in an encoded ULID
+pub const ENCODED_LEN: usize = 26;
+
+/// largest millisecond timestamp, 48 bits
+pub const MAX_TIMESTAMP: u64 = (1 << 48) - 1;
+
+/// Universally Unique Lexicographically Sortable Identifier: a big-endian millisecond timestamp
+/// followed by 80 random bits, encoded as 26 chars of [`Base32Crockford`].
+///
+/// Parsing is case-insensitive, sorting the bytes or the upper case strings sorts by time.
+///
+/// ```rust
+/// use smol_base_x::ulid::Ulid;
+///
+/// let id: Ulid = "01ARYZ6S41TSV4RRFFQ69G5FAV".parse().unwrap();
+/// assert_eq!(id.timestamp_ms(), 1469918176385);
+/// assert_eq!(id.encode().as_str(), "01ARYZ6S41TSV4RRFFQ69G5FAV");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Ulid([u8; BYTES]);
+
+impl Ulid {
+    /// errors with [`DecodeError::Overflow`] if `unix_ms` is above [`MAX_TIMESTAMP`]
+    pub fn new(unix_ms: u64, random: [u8; 10]) -> Result<Self, DecodeError> {
+        if unix_ms > MAX_TIMESTAMP {
+            return Err(DecodeError::Overflow);
+        }
+
+        let mut bytes = [0u8; BYTES];
+        bytes[..6].copy_from_slice(&unix_ms.to_be_bytes()[2..]);
+        bytes[6..].copy_from_slice(&random);
+        Ok(Ulid(bytes))
+    }
+
+    /// [`Ulid::new`] with the random part filled in by `fill`, e.g. a CSPRNG's `fill_bytes`
+    pub fn generate(unix_ms: u64, fill: impl FnOnce(&mut [u8])) -> Result<Self, DecodeError> {
+        let mut random = [0u8; 10];
+        fill(&mut random);
+        Self::new(unix_ms, random)
+    }
+
+    pub fn from_bytes(bytes: [u8; BYTES]) -> Self {
+        Ulid(bytes)
+    }
+
+    pub fn as_bytes(&self) -> &[u8; BYTES] {
+        &self.0
+    }
+
+    /// milliseconds since the unix epoch
+    pub fn timestamp_ms(&self) -> u64 {
+        let mut timestamp = [0u8; 8];
+        timestamp[2..].copy_from_slice(&self.0[..6]);
+        u64::from_be_bytes(timestamp)
+    }
+
+    pub fn random(&self) -> &[u8] {
+        &self.0[6..]
+    }
+
+    pub fn encode(&self) -> StackStr<ENCODED_LEN> {
+        let mut buf = [0u8; ENCODED_LEN];
+        Base32Crockford::encode_sortable_mut(self.0, &mut buf)
+            .expect("16 bytes always fit in 26 base32 chars");
+        StackStr::from_tail(buf, ENCODED_LEN)
+    }
+
+    /// parses the 26 char form, a first char above `7` is rejected with [`DecodeError::Overflow`]
+    pub fn parse<I: AsRef<[u8]>>(input: I) -> Result<Self, DecodeError> {
+        let input = input.as_ref();
+
+        if input.len() != ENCODED_LEN {
+            return Err(DecodeError::InvalidLength(ENCODED_LEN));
+        }
+
+        let mut bytes = [0u8; BYTES];
+        Base32Crockford::decode_fixed_mut(input, &mut bytes)?;
+        Ok(Ulid(bytes))
+    }
+}
+
+impl core::str::FromStr for Ulid {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl fmt::Display for Ulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
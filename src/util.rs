@@ -17,6 +17,142 @@ pub fn encoded_size(base: usize, input_byte_size: usize) -> usize {
     (input_byte_size as f32 * (log10(256_usize) / log10(base)) + 1.0) as usize
 }
 
+/// generates the ceiling of the symbols of base `to` that `symbols` of base `from` convert to,
+/// given as `symbols * (log10(from) / log10(to))`, see [`crate::transcode`]
+///
+/// **This is rounded up** it will sometimes say it needs one symbol more than it needs
+pub fn transcoded_size(from: usize, to: usize, symbols: usize) -> usize {
+    (symbols as f64 * (log10_f64(from) / log10_f64(to)) + 1.0) as usize
+}
+
+/// ceiling of the chars basE91 encodes `input_byte_size` bytes to.
+/// Every pair of chars carries at least 13 bits, plus up to 2 chars for whatever is left over
+pub fn base91_encoded_size(input_byte_size: usize) -> usize {
+    input_byte_size * 8 / 13 * 2 + 2
+}
+
+/// ceiling of the bytes basE91 decodes `input_char_size` chars to.
+/// Every pair of chars carries at most 14 bits, plus a byte for an unpaired char
+pub fn base91_decoded_size(input_char_size: usize) -> usize {
+    input_char_size / 2 * 14 / 8 + 1
+}
+
+/// size of `encoded_len` symbols once a separator is placed between every `group` of them,
+/// as written by [`crate::Base::encode_grouped_mut`]
+pub fn grouped_size(encoded_len: usize, group: usize) -> usize {
+    if group == 0 || encoded_len == 0 {
+        return encoded_len;
+    }
+    encoded_len + (encoded_len - 1) / group
+}
+
+/// largest block [`crate::Base::encode_blocks_mut`] accepts, in bytes
+pub const MAX_BLOCK_SIZE: usize = 64;
+
+/// exact symbols a block of `bytes` encodes to in block mode,
+/// the smallest `w` with `base^w >= 256^bytes`.
+///
+/// Unlike [`encoded_size`] this is computed with integers so it is never off by one.
+/// `bytes` must be at most [`MAX_BLOCK_SIZE`].
+pub const fn block_encoded_size(base: usize, bytes: usize) -> usize {
+    assert!(base >= 2 && base <= 256, "base must be within 2..=256");
+    assert!(
+        bytes <= MAX_BLOCK_SIZE,
+        "block is larger than MAX_BLOCK_SIZE"
+    );
+
+    match symbols_for_bits(base, bytes * 8) {
+        Some(symbols) => symbols,
+        None => unreachable!(),
+    }
+}
+
+/// most bits [`symbols_for_bits`] works out, far past any entropy target in practice
+pub const MAX_ENTROPY_BITS: usize = 4096;
+
+/// fewest symbols of `base` that carry at least `bits` of entropy, the smallest `n` with `base^n >= 2^bits`.
+///
+/// Use it to size random strings, `Base::random_string` with 22 base62 symbols is at least 128 bits.
+/// `None` if `base` isn't within `2..=256` or `bits` is over [`MAX_ENTROPY_BITS`].
+///
+/// ```rust
+/// use smol_base_x::util::symbols_for_bits;
+///
+/// assert_eq!(symbols_for_bits(58, 128), Some(22));
+/// assert_eq!(symbols_for_bits(16, 128), Some(32));
+/// assert_eq!(symbols_for_bits(62, 1024), Some(172));
+/// ```
+pub const fn symbols_for_bits(base: usize, bits: usize) -> Option<usize> {
+    if base < 2 || base > 256 || bits > MAX_ENTROPY_BITS {
+        return None;
+    }
+
+    // base^w as little endian u32 limbs, one limb of headroom above 2^MAX_ENTROPY_BITS
+    const LIMBS: usize = MAX_ENTROPY_BITS / 32 + 1;
+    let mut power = [0u32; LIMBS];
+    power[0] = 1;
+    // limbs that can be non zero, so small sizes don't walk the whole array
+    let mut used = 1;
+
+    let mut symbols = 0;
+    while !at_least_bits(&power, bits) {
+        let mut carry = 0u64;
+        let mut i = 0;
+        while i < used {
+            let limb = power[i] as u64 * base as u64 + carry;
+            power[i] = limb as u32;
+            carry = limb >> 32;
+            i += 1;
+        }
+        if carry != 0 {
+            power[used] = carry as u32;
+            used += 1;
+        }
+        symbols += 1;
+    }
+
+    Some(symbols)
+}
+
+/// bits of entropy in `symbols` picked uniformly from `base`, `symbols * log2(base)`.
+///
+/// **This is approximate**, it uses the same float log as [`encoded_size`]
+pub fn entropy_bits(base: usize, symbols: usize) -> f32 {
+    symbols as f32 * (log10(base) / log10(2))
+}
+
+/// exact bytes a block of `symbols` decodes to in block mode, `None` if no block encodes to that many.
+pub fn block_decoded_size(base: usize, symbols: usize) -> Option<usize> {
+    (0..=MAX_BLOCK_SIZE)
+        .take_while(|&bytes| block_encoded_size(base, bytes) <= symbols)
+        .find(|&bytes| block_encoded_size(base, bytes) == symbols)
+}
+
+/// symbols are in strictly ascending ASCII order, so comparing encodings of the same width
+/// as strings gives the same order as comparing the numbers
+pub const fn is_ascending(alphabet: &[u8]) -> bool {
+    let mut i = 1;
+    while i < alphabet.len() {
+        if alphabet[i - 1] >= alphabet[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// `value >= 2^bits` for little endian limbs
+const fn at_least_bits(value: &[u32], bits: usize) -> bool {
+    let mut i = value.len();
+    while i > 0 {
+        i -= 1;
+        if value[i] != 0 {
+            return (i + 1) * 32 - value[i].leading_zeros() as usize > bits;
+        }
+    }
+    false
+}
+
 #[cfg(feature = "unstable")]
 /// takes an array of ascii chars and fills a char array of the same length
 /// shouldnt be necessary for users as Base<BASE> has a blanket impl for `UtfBase<BASE>`
@@ -93,6 +229,28 @@ fn log10(x: usize) -> f32 {
     ln(x as f32) / core::f32::consts::LN_10
 }
 
+/// [`ln`] in f64, f32 rounds too far for estimates over thousands of symbols
+fn ln_f64(x: f64) -> f64 {
+    let mut old_sum = 0.0;
+    let xmlxpl = (x - 1.0) / (x + 1.0);
+    let xmlxpl_2 = xmlxpl * xmlxpl;
+    let mut denom = 1.0;
+    let mut frac = xmlxpl;
+    let mut sum = frac;
+
+    while sum != old_sum {
+        old_sum = sum;
+        denom += 2.0;
+        frac *= xmlxpl_2;
+        sum += frac / denom;
+    }
+    2.0 * sum
+}
+
+fn log10_f64(x: usize) -> f64 {
+    ln_f64(x as f64) / core::f64::consts::LN_10
+}
+
 #[cfg(feature = "unstable")]
 pub(crate) mod const_arr_size {
 